@@ -39,14 +39,7 @@ pub struct EvidenceScalar(f64);
 
 impl EvidenceScalar {
     pub fn new(v: f64) -> Self {
-        let clamped = if v < 0.0 {
-            0.0
-        } else if v > 1.0 {
-            1.0
-        } else {
-            v
-        };
-        Self(clamped)
+        Self(v.clamp(0.0, 1.0))
     }
 
     pub fn value(self) -> f64 {
@@ -102,19 +95,14 @@ impl BiosafePolytope {
 }
 
 /// FateWindow state, for log-only diagnostic intervals.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub enum FateWindowState {
+    #[default]
     Closed,
     Open,
     Invalidated,
 }
 
-impl Default for FateWindowState {
-    fn default() -> Self {
-        FateWindowState::Closed
-    }
-}
-
 /// FateWindow metadata used for audits and CI checks.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FateWindow {
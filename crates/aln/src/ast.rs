@@ -0,0 +1,86 @@
+/// Which of the two shard layouts a file uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    /// `SECTION,...` / `ROW,...` / `FOOTER,...` records, as in
+    /// `QUANTUM-SYNTHESIS-CONSTRAINTS.aln`.
+    Sectioned,
+    /// An `aln` header line followed by bare block names (`meta`, `policy`,
+    /// `rows`) holding `key value` entries or comma-separated rows, as in
+    /// `qpudata/shards/*.tolnp.aln`.
+    Block,
+}
+
+/// A parsed shard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shard {
+    pub dialect: Dialect,
+    pub hexstamp: Option<Hexstamp>,
+    pub sections: Vec<Section>,
+    pub footer: Option<Footer>,
+}
+
+impl Shard {
+    /// First section called `name`.
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|s| s.name == name)
+    }
+
+    /// Every row of every section, in file order.
+    pub fn rows(&self) -> impl Iterator<Item = &Row> {
+        self.sections.iter().flat_map(|s| s.rows.iter())
+    }
+}
+
+/// `hexstamp 0x...` line identifying a shard revision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hexstamp {
+    pub value: String,
+    pub line: usize,
+}
+
+/// `SECTION,<name>,<args...>`, or a bare block name in the block dialect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub name: String,
+    pub args: Vec<String>,
+    pub line: usize,
+    pub rows: Vec<Row>,
+}
+
+impl Section {
+    /// Rows whose first field is `kind`.
+    pub fn rows_of<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a Row> {
+        self.rows.iter().filter(move |r| r.kind() == kind)
+    }
+}
+
+/// One logical row with continuation lines already joined.
+///
+/// Fields are split on top-level commas (commas inside parentheses stay in
+/// their field) and trimmed; empty fields are kept. The `ROW` keyword itself
+/// is not a field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub fields: Vec<String>,
+    /// Line the row starts on (1-based).
+    pub line: usize,
+}
+
+impl Row {
+    /// First field, e.g. `CONSTRAINT` or `role`; empty for an empty row.
+    pub fn kind(&self) -> &str {
+        self.fields.first().map(String::as_str).unwrap_or("")
+    }
+
+    pub fn field(&self, index: usize) -> Option<&str> {
+        self.fields.get(index).map(String::as_str)
+    }
+}
+
+/// `FOOTER,<label>` or a bare `END`; nothing may follow it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Footer {
+    /// `None` for a bare `END`.
+    pub label: Option<String>,
+    pub line: usize,
+}
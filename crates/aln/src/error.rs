@@ -0,0 +1,55 @@
+use thiserror::Error;
+
+/// A shard that could not be parsed, with the 1-based line it failed on.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("line {line}: {kind}")]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseErrorKind {
+    #[error("ROW before any SECTION")]
+    RowOutsideSection,
+
+    #[error("unknown directive {0:?}")]
+    UnknownDirective(String),
+
+    #[error("SECTION without a name")]
+    EmptySectionName,
+
+    #[error("record ends with a dangling comma")]
+    DanglingComma,
+
+    #[error("unbalanced parentheses")]
+    UnbalancedParens,
+
+    #[error("invalid hexstamp {0:?}; expected 0x followed by letters, digits or '-'")]
+    InvalidHexstamp(String),
+
+    #[error("second hexstamp; the first is on line {0}")]
+    DuplicateHexstamp(usize),
+
+    #[error("content after footer")]
+    ContentAfterFooter,
+
+    #[error("block dialect entry before any block name")]
+    EntryOutsideBlock,
+}
+
+/// Failure to load a shard file.
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("cannot read shard: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+impl ParseErrorKind {
+    pub(crate) fn at(self, line: usize) -> ParseError {
+        ParseError { line, kind: self }
+    }
+}
@@ -0,0 +1,366 @@
+//! Boolean expressions used by `ROW,PREDICATE,<name>,<expr>` rows.
+//!
+//! Grammar, loosest binding first:
+//!
+//! ```text
+//! or      := and ("||" and)*
+//! and     := cmp ("&&" cmp)*
+//! cmp     := unary (("==" | "!=" | "<" | "<=" | ">" | ">=") unary)?
+//! unary   := "!" unary | primary
+//! primary := number | "true" | "false" | variable | "(" or ")"
+//! ```
+//!
+//! Variables are dotted names such as `FATEWINDOW.ROH_MAX`, looked up in a
+//! binding map. `true`/`false` are case-insensitive. `&&` and `||`
+//! short-circuit.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::ast::Section;
+
+/// A value an expression variable can be bound to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Number(f64),
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(x: f64) -> Self {
+        Value::Number(x)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(x: f32) -> Self {
+        Value::Number(x.into())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Number(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+/// Variable name to value.
+pub type Bindings = BTreeMap<String, Value>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl fmt::Display for CmpOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CmpOp::Eq => "==",
+            CmpOp::Ne => "!=",
+            CmpOp::Lt => "<",
+            CmpOp::Le => "<=",
+            CmpOp::Gt => ">",
+            CmpOp::Ge => ">=",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Parsed expression tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Literal(Value),
+    Var(String),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Cmp(CmpOp, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ExprError {
+    #[error("unexpected character {0:?}")]
+    UnexpectedChar(char),
+
+    #[error("unexpected {0:?}")]
+    UnexpectedToken(String),
+
+    #[error("expression ends early")]
+    UnexpectedEnd,
+
+    #[error("unbound variable {0}")]
+    Unbound(String),
+
+    #[error("{op} needs {expected} operands")]
+    TypeMismatch { op: String, expected: &'static str },
+}
+
+/// A PREDICATE row that failed to parse or evaluate.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("line {line}: predicate {name}: {source}")]
+pub struct PredicateError {
+    pub line: usize,
+    pub name: String,
+    pub source: ExprError,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(f64),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, ExprError> {
+    const OPS: [&str; 9] = ["&&", "||", "==", "!=", "<=", ">=", "<", ">", "!"];
+    let mut tokens = Vec::new();
+    let mut rest = text.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let len = if let Some(op) = OPS.iter().find(|op| rest.starts_with(**op)) {
+            tokens.push(Token::Op(op));
+            op.len()
+        } else if c == '(' || c == ')' {
+            tokens.push(if c == '(' {
+                Token::LParen
+            } else {
+                Token::RParen
+            });
+            1
+        } else if c.is_ascii_digit()
+            || (c == '-' && rest[1..].starts_with(|d: char| d.is_ascii_digit()))
+        {
+            let len = 1 + rest[1..]
+                .find(|d: char| !(d.is_ascii_digit() || d == '.'))
+                .unwrap_or(rest.len() - 1);
+            let num = rest[..len]
+                .parse()
+                .map_err(|_| ExprError::UnexpectedToken(rest[..len].to_string()))?;
+            tokens.push(Token::Num(num));
+            len
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let len = rest
+                .find(|d: char| !(d.is_ascii_alphanumeric() || d == '_' || d == '.'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Ident(rest[..len].to_string()));
+            len
+        } else {
+            return Err(ExprError::UnexpectedChar(c));
+        };
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, ExprError> {
+        let token = self.peek().cloned().ok_or(ExprError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat_op(&mut self, op: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Op(o)) if *o == op);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn or(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.and()?;
+        while self.eat_op("||") {
+            lhs = Expr::Or(Box::new(lhs), Box::new(self.and()?));
+        }
+        Ok(lhs)
+    }
+
+    fn and(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.cmp()?;
+        while self.eat_op("&&") {
+            lhs = Expr::And(Box::new(lhs), Box::new(self.cmp()?));
+        }
+        Ok(lhs)
+    }
+
+    fn cmp(&mut self) -> Result<Expr, ExprError> {
+        let lhs = self.unary()?;
+        let op = match self.peek() {
+            Some(Token::Op("==")) => CmpOp::Eq,
+            Some(Token::Op("!=")) => CmpOp::Ne,
+            Some(Token::Op("<")) => CmpOp::Lt,
+            Some(Token::Op("<=")) => CmpOp::Le,
+            Some(Token::Op(">")) => CmpOp::Gt,
+            Some(Token::Op(">=")) => CmpOp::Ge,
+            _ => return Ok(lhs),
+        };
+        self.pos += 1;
+        Ok(Expr::Cmp(op, Box::new(lhs), Box::new(self.unary()?)))
+    }
+
+    fn unary(&mut self) -> Result<Expr, ExprError> {
+        if self.eat_op("!") {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        match self.next()? {
+            Token::Num(x) => Ok(Expr::Literal(Value::Number(x))),
+            Token::Ident(name) if name.eq_ignore_ascii_case("true") => {
+                Ok(Expr::Literal(Value::Bool(true)))
+            }
+            Token::Ident(name) if name.eq_ignore_ascii_case("false") => {
+                Ok(Expr::Literal(Value::Bool(false)))
+            }
+            Token::Ident(name) => Ok(Expr::Var(name)),
+            Token::LParen => {
+                let inner = self.or()?;
+                match self.next()? {
+                    Token::RParen => Ok(inner),
+                    other => Err(unexpected(&other)),
+                }
+            }
+            other => Err(unexpected(&other)),
+        }
+    }
+}
+
+fn unexpected(token: &Token) -> ExprError {
+    ExprError::UnexpectedToken(match token {
+        Token::Num(x) => x.to_string(),
+        Token::Ident(name) => name.clone(),
+        Token::Op(op) => op.to_string(),
+        Token::LParen => "(".to_string(),
+        Token::RParen => ")".to_string(),
+    })
+}
+
+impl Expr {
+    pub fn parse(text: &str) -> Result<Self, ExprError> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            pos: 0,
+        };
+        let expr = parser.or()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(token) => Err(unexpected(token)),
+        }
+    }
+
+    pub fn eval(&self, bindings: &Bindings) -> Result<Value, ExprError> {
+        match self {
+            Expr::Literal(v) => Ok(*v),
+            Expr::Var(name) => bindings
+                .get(name)
+                .copied()
+                .ok_or_else(|| ExprError::Unbound(name.clone())),
+            Expr::Not(e) => Ok(Value::Bool(!e.eval_bool("!", bindings)?)),
+            Expr::And(a, b) => Ok(Value::Bool(
+                a.eval_bool("&&", bindings)? && b.eval_bool("&&", bindings)?,
+            )),
+            Expr::Or(a, b) => Ok(Value::Bool(
+                a.eval_bool("||", bindings)? || b.eval_bool("||", bindings)?,
+            )),
+            Expr::Cmp(op, a, b) => {
+                let result = match (*op, a.eval(bindings)?, b.eval(bindings)?) {
+                    (CmpOp::Eq, x, y) if same_kind(x, y) => x == y,
+                    (CmpOp::Ne, x, y) if same_kind(x, y) => x != y,
+                    (op, Value::Number(x), Value::Number(y)) => match op {
+                        CmpOp::Lt => x < y,
+                        CmpOp::Le => x <= y,
+                        CmpOp::Gt => x > y,
+                        CmpOp::Ge => x >= y,
+                        CmpOp::Eq | CmpOp::Ne => unreachable!("handled above"),
+                    },
+                    (op, _, _) => {
+                        let expected = match op {
+                            CmpOp::Eq | CmpOp::Ne => "matching",
+                            _ => "numeric",
+                        };
+                        return Err(ExprError::TypeMismatch {
+                            op: op.to_string(),
+                            expected,
+                        });
+                    }
+                };
+                Ok(Value::Bool(result))
+            }
+        }
+    }
+
+    fn eval_bool(&self, op: &str, bindings: &Bindings) -> Result<bool, ExprError> {
+        match self.eval(bindings)? {
+            Value::Bool(b) => Ok(b),
+            Value::Number(_) => Err(ExprError::TypeMismatch {
+                op: op.to_string(),
+                expected: "boolean",
+            }),
+        }
+    }
+}
+
+fn same_kind(a: Value, b: Value) -> bool {
+    matches!(
+        (a, b),
+        (Value::Bool(_), Value::Bool(_)) | (Value::Number(_), Value::Number(_))
+    )
+}
+
+/// Evaluate every `PREDICATE` row of `section` in file order.
+///
+/// Each result is bound under the predicate's name before the next row is
+/// evaluated, so later predicates can combine earlier ones. Returns the
+/// predicate values by name; `bindings` is not modified.
+pub fn evaluate_predicates(
+    section: &Section,
+    bindings: &Bindings,
+) -> Result<BTreeMap<String, bool>, PredicateError> {
+    let mut scope = bindings.clone();
+    let mut results = BTreeMap::new();
+    for row in section.rows_of("PREDICATE") {
+        let name = row.field(1).unwrap_or_default().to_string();
+        let fail = |source| PredicateError {
+            line: row.line,
+            name: name.clone(),
+            source,
+        };
+        let expr = Expr::parse(row.field(2).unwrap_or_default()).map_err(fail)?;
+        let value = match expr.eval(&scope).map_err(fail)? {
+            Value::Bool(b) => b,
+            Value::Number(_) => {
+                return Err(fail(ExprError::TypeMismatch {
+                    op: "PREDICATE".to_string(),
+                    expected: "boolean",
+                }))
+            }
+        };
+        scope.insert(name.clone(), Value::Bool(value));
+        results.insert(name, value);
+    }
+    Ok(results)
+}
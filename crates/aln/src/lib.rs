@@ -0,0 +1,30 @@
+//! Parser for ALN shards: the comma-separated, line-oriented doctrine and
+//! data files under `aln/`, `qpudata/shards/` and the repository root.
+//!
+//! Two layouts are in use and both parse into the same [`Shard`] AST:
+//! - the sectioned dialect: an optional `hexstamp 0x...` line, then
+//!   `SECTION,<name>,...` headers, each followed by `ROW,...` records, and an
+//!   optional `FOOTER,<label>` or bare `END`;
+//! - the block dialect: an `aln` header line, then bare block names (`meta`,
+//!   `policy`, `rows`) holding `key value` entries or comma-separated rows.
+//!
+//! Parsing is purely syntactic; what a row means is up to the consumer.
+//! The one exception is [`expr`], which evaluates the boolean expressions of
+//! `PREDICATE` rows against caller-supplied bindings.
+
+mod ast;
+mod error;
+pub mod expr;
+mod parser;
+
+pub use ast::{Dialect, Footer, Hexstamp, Row, Section, Shard};
+pub use error::{LoadError, ParseError, ParseErrorKind};
+pub use parser::parse;
+
+use std::fs;
+use std::path::Path;
+
+/// Read and parse a shard file.
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<Shard, LoadError> {
+    Ok(parse(&fs::read_to_string(path)?)?)
+}
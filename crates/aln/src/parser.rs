@@ -0,0 +1,330 @@
+use crate::ast::{Dialect, Footer, Hexstamp, Row, Section, Shard};
+use crate::error::{ParseError, ParseErrorKind};
+
+/// One physical line with its comment removed.
+struct SourceLine<'a> {
+    number: usize,
+    content: &'a str,
+    indented: bool,
+    blank: bool,
+}
+
+fn strip_comment(raw: &str) -> &str {
+    if raw.trim_start().starts_with('#') {
+        return "";
+    }
+    let end = [raw.find(';'), raw.find("--")]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(raw.len());
+    raw[..end].trim()
+}
+
+fn source_lines(text: &str) -> impl Iterator<Item = SourceLine<'_>> {
+    text.lines().enumerate().map(|(i, raw)| SourceLine {
+        number: i + 1,
+        content: strip_comment(raw),
+        indented: raw.starts_with([' ', '\t']),
+        blank: raw.trim().is_empty(),
+    })
+}
+
+/// Leading token of a line: everything before the first comma or whitespace.
+fn leading_token(content: &str) -> &str {
+    let end = content
+        .find(|c: char| c == ',' || c.is_whitespace())
+        .unwrap_or(content.len());
+    &content[..end]
+}
+
+/// Split on commas outside parentheses, trimming each field.
+fn split_fields(text: &str, line: usize) -> Result<Vec<String>, ParseError> {
+    let mut fields = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| ParseErrorKind::UnbalancedParens.at(line))?
+            }
+            ',' if depth == 0 => {
+                fields.push(text[start..i].trim().to_string());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(ParseErrorKind::UnbalancedParens.at(line));
+    }
+    fields.push(text[start..].trim().to_string());
+    Ok(fields)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Directive {
+    Hexstamp,
+    Section,
+    Row,
+    Footer,
+}
+
+fn directive(token: &str) -> Option<Directive> {
+    match token {
+        "SECTION" => Some(Directive::Section),
+        "ROW" => Some(Directive::Row),
+        "FOOTER" | "END" => Some(Directive::Footer),
+        t if t.eq_ignore_ascii_case("hexstamp") => Some(Directive::Hexstamp),
+        _ => None,
+    }
+}
+
+/// A logical record being assembled from one or more physical lines.
+struct Record {
+    directive: Option<Directive>,
+    line: usize,
+    text: String,
+}
+
+impl Record {
+    fn new(directive: Option<Directive>, line: &SourceLine<'_>) -> Self {
+        Self {
+            directive,
+            line: line.number,
+            text: line.content.to_string(),
+        }
+    }
+
+    fn dangling(&self) -> bool {
+        self.text.ends_with(',')
+    }
+
+    fn continue_with(&mut self, content: &str) {
+        if !self.dangling() {
+            self.text.push(' ');
+        }
+        self.text.push_str(content);
+    }
+}
+
+/// Accumulates records into a `Shard`.
+struct Builder {
+    shard: Shard,
+}
+
+impl Builder {
+    fn new(dialect: Dialect) -> Self {
+        Self {
+            shard: Shard {
+                dialect,
+                hexstamp: None,
+                sections: Vec::new(),
+                footer: None,
+            },
+        }
+    }
+
+    fn push(&mut self, record: Record) -> Result<(), ParseError> {
+        let line = record.line;
+        if record.dangling() {
+            return Err(ParseErrorKind::DanglingComma.at(line));
+        }
+        if self.shard.footer.is_some() {
+            return Err(ParseErrorKind::ContentAfterFooter.at(line));
+        }
+        match record.directive {
+            Some(Directive::Hexstamp) => self.hexstamp(&record.text, line),
+            Some(Directive::Section) => {
+                let mut fields = split_fields(&record.text, line)?.into_iter().skip(1);
+                let name = fields.next().unwrap_or_default();
+                if name.is_empty() {
+                    return Err(ParseErrorKind::EmptySectionName.at(line));
+                }
+                self.section(name, fields.collect(), line)
+            }
+            Some(Directive::Row) => {
+                let fields = split_fields(&record.text, line)?.split_off(1);
+                self.row(fields, line, ParseErrorKind::RowOutsideSection)
+            }
+            Some(Directive::Footer) => {
+                let fields = split_fields(&record.text, line)?;
+                let label = fields[1..].join(",");
+                self.shard.footer = Some(Footer {
+                    label: (!label.is_empty()).then_some(label),
+                    line,
+                });
+                Ok(())
+            }
+            None => {
+                let fields = split_fields(&record.text, line)?;
+                self.row(fields, line, ParseErrorKind::EntryOutsideBlock)
+            }
+        }
+    }
+
+    fn hexstamp(&mut self, text: &str, line: usize) -> Result<(), ParseError> {
+        if let Some(first) = &self.shard.hexstamp {
+            return Err(ParseErrorKind::DuplicateHexstamp(first.line).at(line));
+        }
+        let value = text[leading_token(text).len()..]
+            .trim_start_matches(',')
+            .trim();
+        let valid = value.strip_prefix("0x").is_some_and(|rest| {
+            !rest.is_empty() && rest.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+        if !valid {
+            return Err(ParseErrorKind::InvalidHexstamp(value.to_string()).at(line));
+        }
+        self.shard.hexstamp = Some(Hexstamp {
+            value: value.to_string(),
+            line,
+        });
+        Ok(())
+    }
+
+    fn section(&mut self, name: String, args: Vec<String>, line: usize) -> Result<(), ParseError> {
+        if self.shard.footer.is_some() {
+            return Err(ParseErrorKind::ContentAfterFooter.at(line));
+        }
+        self.shard.sections.push(Section {
+            name,
+            args,
+            line,
+            rows: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn row(
+        &mut self,
+        fields: Vec<String>,
+        line: usize,
+        orphan: ParseErrorKind,
+    ) -> Result<(), ParseError> {
+        if self.shard.footer.is_some() {
+            return Err(ParseErrorKind::ContentAfterFooter.at(line));
+        }
+        let section = self
+            .shard
+            .sections
+            .last_mut()
+            .ok_or_else(|| orphan.at(line))?;
+        section.rows.push(Row { fields, line });
+        Ok(())
+    }
+
+    fn finish(mut self, pending: Option<Record>) -> Result<Shard, ParseError> {
+        if let Some(record) = pending {
+            self.push(record)?;
+        }
+        Ok(self.shard)
+    }
+}
+
+/// Parse a shard in either dialect.
+///
+/// Comments start at `;` or `--` anywhere on a line, or at a `#` that opens
+/// the line. A record continues onto the next line when it ends with a comma,
+/// or, in the sectioned dialect, when it is a `ROW` and the next line is
+/// indented. An indented directive inside a comma-continued record is data;
+/// an unindented one starts a new record. A blank line ends a record that
+/// does not end with a comma.
+pub fn parse(text: &str) -> Result<Shard, ParseError> {
+    let is_block = source_lines(text)
+        .filter(|l| !l.content.is_empty())
+        .find(|l| directive(leading_token(l.content)) != Some(Directive::Hexstamp))
+        .is_some_and(|l| l.content == "aln");
+    if is_block {
+        parse_block(text)
+    } else {
+        parse_sectioned(text)
+    }
+}
+
+fn parse_sectioned(text: &str) -> Result<Shard, ParseError> {
+    let mut builder = Builder::new(Dialect::Sectioned);
+    let mut pending: Option<Record> = None;
+
+    for line in source_lines(text) {
+        if line.content.is_empty() {
+            if line.blank && !pending.as_ref().is_some_and(Record::dangling) {
+                if let Some(record) = pending.take() {
+                    builder.push(record)?;
+                }
+            }
+            continue;
+        }
+
+        let token = leading_token(line.content);
+        let dangling = pending.as_ref().is_some_and(Record::dangling);
+        if let Some(d) = directive(token).filter(|_| !(dangling && line.indented)) {
+            if let Some(record) = pending.take() {
+                builder.push(record)?;
+            }
+            pending = Some(Record::new(Some(d), &line));
+            continue;
+        }
+        match pending.as_mut() {
+            Some(record)
+                if record.dangling()
+                    || (line.indented && record.directive == Some(Directive::Row)) =>
+            {
+                record.continue_with(line.content);
+            }
+            _ => {
+                return Err(ParseErrorKind::UnknownDirective(token.to_string()).at(line.number));
+            }
+        }
+    }
+    builder.finish(pending)
+}
+
+fn parse_block(text: &str) -> Result<Shard, ParseError> {
+    let mut builder = Builder::new(Dialect::Block);
+    let mut pending: Option<Record> = None;
+    let mut seen_header = false;
+
+    for line in source_lines(text) {
+        if line.content.is_empty() {
+            if line.blank && !pending.as_ref().is_some_and(Record::dangling) {
+                if let Some(record) = pending.take() {
+                    builder.push(record)?;
+                }
+            }
+            continue;
+        }
+        if let Some(record) = pending.as_mut().filter(|r| r.dangling()) {
+            record.continue_with(line.content);
+            continue;
+        }
+        if let Some(record) = pending.take() {
+            builder.push(record)?;
+        }
+
+        let content = line.content;
+        let token = leading_token(content);
+        let directive = directive(token).filter(|_| !line.indented);
+        if directive.is_some() {
+            pending = Some(Record::new(directive, &line));
+        } else if !seen_header {
+            // `parse` only routes here when this is the `aln` header.
+            seen_header = true;
+        } else if !line.indented && token.len() == content.len() {
+            builder.section(content.to_string(), Vec::new(), line.number)?;
+        } else if content[token.len()..].starts_with(',') || token.len() == content.len() {
+            pending = Some(Record::new(None, &line));
+        } else {
+            let value = content[token.len()..].trim();
+            builder.row(
+                vec![token.to_string(), value.to_string()],
+                line.number,
+                ParseErrorKind::EntryOutsideBlock,
+            )?;
+        }
+    }
+    builder.finish(pending)
+}
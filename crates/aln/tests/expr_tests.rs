@@ -0,0 +1,73 @@
+use aln::expr::{Bindings, Expr, ExprError, Value};
+use aln::{parse, ParseError};
+
+fn env() -> Bindings {
+    [
+        ("A.X", Value::Number(0.25)),
+        ("A.Y", Value::Number(0.5)),
+        ("FLAG", Value::Bool(true)),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v))
+    .collect()
+}
+
+fn eval(text: &str) -> Result<Value, ExprError> {
+    Expr::parse(text)?.eval(&env())
+}
+
+#[test]
+fn test_comparisons_and_connectives() {
+    assert_eq!(eval("A.X <= A.Y"), Ok(Value::Bool(true)));
+    assert_eq!(eval("A.X >= 0.3"), Ok(Value::Bool(false)));
+    assert_eq!(eval("FLAG == TRUE && A.Y > -1"), Ok(Value::Bool(true)));
+    assert_eq!(eval("A.X > A.Y || !FLAG"), Ok(Value::Bool(false)));
+    // && binds tighter than ||.
+    assert_eq!(eval("true || false && false"), Ok(Value::Bool(true)));
+    assert_eq!(eval("(true || false) && false"), Ok(Value::Bool(false)));
+    assert_eq!(eval("A.X != 0.25"), Ok(Value::Bool(false)));
+}
+
+#[test]
+fn test_expression_errors() {
+    assert_eq!(
+        eval("MISSING > 1"),
+        Err(ExprError::Unbound("MISSING".into()))
+    );
+    assert!(matches!(
+        eval("FLAG < 1"),
+        Err(ExprError::TypeMismatch { .. })
+    ));
+    assert!(matches!(
+        eval("A.X && FLAG"),
+        Err(ExprError::TypeMismatch { .. })
+    ));
+    assert_eq!(eval("A.X <="), Err(ExprError::UnexpectedEnd));
+    assert_eq!(eval("A.X $ 1"), Err(ExprError::UnexpectedChar('$')));
+    assert_eq!(eval("(FLAG"), Err(ExprError::UnexpectedEnd));
+}
+
+#[test]
+fn test_predicates_see_earlier_predicates() {
+    let shard = parse(
+        "SECTION,S\n\
+         ROW,PREDICATE,LOW,\n  A.X < A.Y\n\
+         ROW,PREDICATE,BOTH,\n  LOW && FLAG\n\
+         ROW,PREDICATE,BAD,\n  A.X < NOPE\n",
+    )
+    .map_err(|e: ParseError| e.to_string())
+    .unwrap();
+    let section = &shard.sections[0];
+
+    let err = aln::expr::evaluate_predicates(section, &env()).unwrap_err();
+    assert_eq!(err.line, 6);
+    assert_eq!(err.name, "BAD");
+    assert_eq!(err.source, ExprError::Unbound("NOPE".into()));
+
+    let mut bindings = env();
+    bindings.insert("NOPE".into(), Value::Number(0.0));
+    let results = aln::expr::evaluate_predicates(section, &bindings).unwrap();
+    assert!(results["LOW"]);
+    assert!(results["BOTH"]);
+    assert!(!results["BAD"]);
+}
@@ -0,0 +1,91 @@
+//! The NATURE.FAIRNESS shard and the compiled gate in policy_engine must agree.
+
+use aln::expr::{evaluate_predicates, Bindings};
+use aln::parse_file;
+use policy_engine::nature_fairness_gate::{
+    eval_nature_fairness, FateWindowSummary, JusticeMetrics,
+};
+
+fn bindings(s: &FateWindowSummary) -> Bindings {
+    [
+        ("FATEWINDOW.VALID", s.valid.into()),
+        ("FATEWINDOW.ROH_MAX", s.roh_max.into()),
+        ("FATEWINDOW.ROH_CEILING", s.roh_ceiling.into()),
+        ("FATEWINDOW.LIFEFORCE_MIN", s.lifeforce_min.into()),
+        ("FATEWINDOW.LIFEFORCE_FLOOR", s.lifeforce_floor.into()),
+        ("FATEWINDOW.UNFAIRDRAIN", s.unfairdrain.into()),
+        ("JUSTICE.ERG", s.justice.erg.into()),
+        ("JUSTICE.ERG_MIN_BAND", s.justice.erg_min_band.into()),
+        ("JUSTICE.TECR", s.justice.tecr.into()),
+        ("JUSTICE.TECR_MAX_BAND", s.justice.tecr_max_band.into()),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v))
+    .collect()
+}
+
+/// Every combination of each input sitting below, on and above its band.
+fn summaries() -> Vec<FateWindowSummary> {
+    let levels = [0.2f32, 0.3, 0.4];
+    let mut out = Vec::new();
+    for valid in [true, false] {
+        for unfairdrain in [false, true] {
+            for roh_max in levels {
+                for lifeforce_min in levels {
+                    for erg in levels {
+                        for tecr in levels {
+                            out.push(FateWindowSummary {
+                                id: format!("fw-{}", out.len()),
+                                valid,
+                                roh_max,
+                                roh_ceiling: 0.3,
+                                lifeforce_min,
+                                lifeforce_floor: 0.3,
+                                unfairdrain,
+                                justice: JusticeMetrics {
+                                    erg,
+                                    erg_min_band: 0.3,
+                                    tecr,
+                                    tecr_max_band: 0.3,
+                                },
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn test_shard_predicates_match_compiled_gate() {
+    let path = format!(
+        "{}/../../aln/nature-fairness-colonization.aln",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let shard = parse_file(path).unwrap();
+    let section = shard.section("NATURE.FAIRNESS-CORRIDOR").unwrap();
+
+    let cases = summaries();
+    let mut passing = 0;
+    for summary in &cases {
+        let shard_eval = evaluate_predicates(section, &bindings(summary)).unwrap();
+        let gate = eval_nature_fairness(summary);
+        let c = gate.components;
+        let expected = [
+            ("NATURE.FAIRNESS.ROH_OK", c.roh_ok),
+            ("NATURE.FAIRNESS.LIFEFORCE_OK", c.lifeforce_ok),
+            ("NATURE.FAIRNESS.UNFAIRDRAIN_OK", c.unfairdrain_ok),
+            ("NATURE.FAIRNESS.ERG_OK", c.erg_ok),
+            ("NATURE.FAIRNESS.TECR_OK", c.tecr_ok),
+            ("NATURE.FAIRNESS", gate.nature_fairness),
+        ];
+        for (name, value) in expected {
+            assert_eq!(shard_eval[name], value, "{} on {:?}", name, summary);
+        }
+        passing += usize::from(gate.nature_fairness);
+    }
+    // The grid must exercise both outcomes of the conjunction.
+    assert!(passing > 0 && passing < cases.len());
+}
@@ -0,0 +1,185 @@
+use aln::{parse, parse_file, Dialect, ParseError, ParseErrorKind};
+
+fn repo_file(path: &str) -> String {
+    format!("{}/../../{}", env!("CARGO_MANIFEST_DIR"), path)
+}
+
+#[test]
+fn test_quantum_constraints_shard() {
+    let shard = parse_file(repo_file("QUANTUM-SYNTHESIS-CONSTRAINTS.aln")).unwrap();
+
+    assert_eq!(shard.dialect, Dialect::Sectioned);
+    assert_eq!(shard.sections.len(), 1);
+    let section = &shard.sections[0];
+    assert_eq!(section.name, "QUANTUM-SYNTHESIS-CONSTRAINTS");
+    assert_eq!(section.args, vec!["V1"]);
+
+    // Continuation rows are joined and trailing comments dropped.
+    let inputs = section.rows_of("INPUTS").next().unwrap();
+    assert_eq!(inputs.fields.len(), 10);
+    assert_eq!(inputs.field(1), Some("TREE.DECAY"));
+    assert_eq!(inputs.field(9), Some("JURIS.TAGS"));
+
+    // Commas inside a call stay in one field.
+    let max_depth = section
+        .rows_of("FORMULA")
+        .find(|r| r.field(1) == Some("MAX_DEPTH"))
+        .unwrap();
+    assert_eq!(
+        max_depth.fields,
+        vec![
+            "FORMULA",
+            "MAX_DEPTH",
+            "INT",
+            "synth_max_depth(CAPABILITY.STATE, JURIS.TAGS, DIAG.ROD.BUDGET)"
+        ]
+    );
+
+    assert_eq!(section.rows_of("CONSTRAINT").count(), 3);
+    assert_eq!(section.rows_of("ENFORCEMENT").count(), 5);
+    assert_eq!(shard.footer.as_ref().unwrap().label, None);
+}
+
+#[test]
+fn test_nature_fairness_shard_joins_indented_predicates() {
+    let shard = parse_file(repo_file("aln/nature-fairness-colonization.aln")).unwrap();
+    let section = shard.section("NATURE.FAIRNESS-CORRIDOR").unwrap();
+
+    let roh_ok = section
+        .rows_of("PREDICATE")
+        .find(|r| r.field(1) == Some("NATURE.FAIRNESS.ROH_OK"))
+        .unwrap();
+    assert_eq!(
+        roh_ok.field(2),
+        Some("FATEWINDOW.VALID == true && FATEWINDOW.ROH_MAX <= FATEWINDOW.ROH_CEILING")
+    );
+    assert_eq!(section.rows_of("PREDICATE").count(), 6);
+    assert!(shard.footer.is_none());
+
+    // An indented SECTION inside a dangling row is data, not a new section.
+    let donut = section.rows_of("DONUTLOOP.ALN.MAP").next().unwrap();
+    assert_eq!(donut.field(1), Some("SECTION"));
+    assert_eq!(donut.field(2), Some("NATURE.FAIRNESS.SUMMARY"));
+}
+
+#[test]
+fn test_reversal_shards_with_hexstamp_and_footer() {
+    let stamped = parse_file(repo_file("ln/particles/policyneuromorph-reversal.aln")).unwrap();
+    let hexstamp = stamped.hexstamp.as_ref().unwrap();
+    assert_eq!(hexstamp.value, "0xREV-A01");
+    assert_eq!(hexstamp.line, 1);
+
+    let plain = parse_file(repo_file("aln/particles/neuromorph_row_composition.aln")).unwrap();
+    assert!(plain.hexstamp.is_none());
+    assert_eq!(
+        plain.footer.as_ref().unwrap().label.as_deref(),
+        Some("END-OF-SHARD")
+    );
+
+    for shard in [&stamped, &plain] {
+        let names: Vec<_> = shard.sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["ROLES", "ROLE-COMPOSITION", "REVERSAL-POLICY"]);
+        let condition = shard
+            .section("REVERSAL-POLICY")
+            .unwrap()
+            .rows
+            .iter()
+            .find(|r| r.field(3) == Some("canrevertcapability"))
+            .unwrap();
+        assert_eq!(condition.fields.len(), 8);
+        assert_eq!(condition.field(5), Some("string"));
+    }
+}
+
+#[test]
+fn test_block_dialect_neuroprint_shard() {
+    let shard = parse_file(repo_file("qpudata/shards/tree-of-life-2026v1.tolnp.aln")).unwrap();
+
+    assert_eq!(shard.dialect, Dialect::Block);
+    let names: Vec<_> = shard.sections.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["meta", "policy", "rows"]);
+
+    let meta = shard.section("meta").unwrap();
+    let kind = meta.rows_of("kind").next().unwrap();
+    assert_eq!(kind.fields, vec!["kind", "tree_of_life_neuroprint"]);
+    let usage = shard
+        .section("policy")
+        .unwrap()
+        .rows_of("usage")
+        .next()
+        .unwrap();
+    assert_eq!(usage.field(1), Some("educational_only,simulation_only"));
+
+    let baseline = shard
+        .section("rows")
+        .unwrap()
+        .rows_of("baseline")
+        .next()
+        .unwrap();
+    assert_eq!(baseline.fields.len(), 16);
+    assert_eq!(baseline.field(1), Some("0.50"));
+    assert_eq!(baseline.field(15), Some("0.05"));
+    assert_eq!(baseline.line, 15);
+}
+
+fn error_of(text: &str) -> ParseError {
+    parse(text).unwrap_err()
+}
+
+#[test]
+fn test_errors_report_line_numbers() {
+    assert_eq!(
+        error_of("; header\nROW,A,B\n"),
+        ParseError {
+            line: 2,
+            kind: ParseErrorKind::RowOutsideSection
+        }
+    );
+    assert_eq!(
+        error_of("SECTION,S\nROW,A\nBOGUS,1\n"),
+        ParseError {
+            line: 3,
+            kind: ParseErrorKind::UnknownDirective("BOGUS".into())
+        }
+    );
+    assert_eq!(
+        error_of("SECTION,S\nROW,A,\n\nROW,B\n").kind,
+        ParseErrorKind::DanglingComma
+    );
+    assert_eq!(
+        error_of("SECTION,S\nROW,F,\n  f(a, b\n"),
+        ParseError {
+            line: 2,
+            kind: ParseErrorKind::UnbalancedParens
+        }
+    );
+    assert_eq!(
+        error_of("hexstamp 0xA\nhexstamp 0xB\n"),
+        ParseError {
+            line: 2,
+            kind: ParseErrorKind::DuplicateHexstamp(1)
+        }
+    );
+    assert_eq!(
+        error_of("hexstamp beef\n").kind,
+        ParseErrorKind::InvalidHexstamp("beef".into())
+    );
+    assert_eq!(
+        error_of("SECTION,S\nFOOTER,END\nROW,A\n"),
+        ParseError {
+            line: 3,
+            kind: ParseErrorKind::ContentAfterFooter
+        }
+    );
+    assert_eq!(
+        error_of("aln\n  key value\n"),
+        ParseError {
+            line: 2,
+            kind: ParseErrorKind::EntryOutsideBlock
+        }
+    );
+    assert_eq!(
+        error_of("SECTION,S\nROW,A\nBOGUS,1\n").to_string(),
+        "line 3: unknown directive \"BOGUS\""
+    );
+}
@@ -0,0 +1,64 @@
+//! Capability lattice and jurisdiction tags.
+//!
+//! These are read-only descriptors: nothing here changes a subject's
+//! capability state, it only names the tiers gates read to pick limits.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+/// Capability tiers, ordered from least to most exposure to humans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum CapabilityState {
+    CapModelOnly,
+    CapLabBench,
+    CapControlledHuman,
+    CapGeneralUse,
+}
+
+impl CapabilityState {
+    /// Every tier, lowest first.
+    pub const ALL: [CapabilityState; 4] = [
+        CapabilityState::CapModelOnly,
+        CapabilityState::CapLabBench,
+        CapabilityState::CapControlledHuman,
+        CapabilityState::CapGeneralUse,
+    ];
+
+    /// Whether humans are exposed at this tier, so the RoH ceiling applies.
+    pub fn governs_humans(self) -> bool {
+        self >= CapabilityState::CapControlledHuman
+    }
+
+    /// Whether moving to `to` lowers the tier.
+    pub fn is_downgrade_to(self, to: CapabilityState) -> bool {
+        to < self
+    }
+}
+
+/// Jurisdiction plus the neurorights bundle in force, as a set of tags
+/// such as `"EU"` or `"neurorights"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JurisdictionTags {
+    tags: BTreeSet<String>,
+}
+
+impl JurisdictionTags {
+    pub fn new<I, S>(tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            tags: tags.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn contains(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.tags.iter().map(String::as_str)
+    }
+}
@@ -0,0 +1,39 @@
+//! Biophysical envelope snapshot schema.
+//!
+//! A snapshot is the already-normalized output of the envelope monitors for
+//! one subject at one instant. Every axis is optional; views decide their own
+//! neutral default for a missing axis. Normalized values and fractions are in
+//! [0, 1].
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BiophysicalEnvelopeSnapshot {
+    // Cardiovascular.
+    #[serde(alias = "hrbpm_normalized")]
+    pub hr_bpm_normalized: Option<f32>,
+    #[serde(alias = "hrvrmssd_normalized")]
+    pub hrv_rmssd_normalized: Option<f32>,
+
+    // EEG bandpower and alpha-envelope coefficient of variation.
+    pub eeg_alpha_power_norm: Option<f32>,
+    pub eeg_beta_power_norm: Option<f32>,
+    pub eeg_gamma_power_norm: Option<f32>,
+    #[serde(alias = "eeg_alpha_cve_normalized")]
+    pub eeg_alpha_cve_norm: Option<f32>,
+
+    // Share of monitored axes at each severity.
+    pub info_axis_fraction: Option<f32>,
+    pub warn_axis_fraction: Option<f32>,
+    pub risk_axis_fraction: Option<f32>,
+    pub active_axis_count: Option<u32>,
+
+    // Per-channel WARN/RISK fractions.
+    pub eda_warn_fraction: Option<f32>,
+    pub eda_risk_fraction: Option<f32>,
+    pub hr_warn_fraction: Option<f32>,
+    pub hr_risk_fraction: Option<f32>,
+    pub motion_warn_fraction: Option<f32>,
+    pub motion_risk_fraction: Option<f32>,
+}
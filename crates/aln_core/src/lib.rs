@@ -0,0 +1,15 @@
+//! Core types shared by the Tree-of-Life views and gates: the capability
+//! lattice, Risk-of-Harm scores with their ceiling, and the biophysical
+//! envelope snapshot schema.
+//!
+//! Everything here is a read-only descriptor. Crates that compute views or
+//! gate decisions import these types instead of defining their own, so a
+//! snapshot serialized by one can be read by all of them.
+
+mod capability;
+mod envelope;
+pub mod roh;
+
+pub use capability::{CapabilityState, JurisdictionTags};
+pub use envelope::BiophysicalEnvelopeSnapshot;
+pub use roh::{RoHProjection, RoHScore, ROH_CEILING};
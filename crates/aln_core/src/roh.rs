@@ -0,0 +1,94 @@
+//! Risk-of-Harm (RoH) scores.
+//!
+//! RoH is a non-negative scalar; governed humans must stay at or below
+//! [`ROH_CEILING`], and a governed transition may not report a lower RoH
+//! after than before. Scores are descriptive only.
+
+use serde::{Deserialize, Serialize};
+
+/// Doctrine ceiling on RoH for governed humans.
+pub const ROH_CEILING: f32 = 0.30;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct RoHScore {
+    value: f32,
+}
+
+impl RoHScore {
+    /// Negative and NaN inputs are stored as 0.0.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value: if value > 0.0 { value } else { 0.0 },
+        }
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn ceiling(&self) -> f32 {
+        ROH_CEILING
+    }
+
+    pub fn within_ceiling(&self) -> bool {
+        self.value <= ROH_CEILING
+    }
+
+    /// Fraction of the ceiling used, saturating at 1.0.
+    pub fn normalized(&self) -> f32 {
+        (self.value / ROH_CEILING).min(1.0)
+    }
+
+    /// RoH may only be reported as rising or flat across a transition.
+    pub fn is_monotone_from(&self, before: RoHScore) -> bool {
+        self.value >= before.value
+    }
+}
+
+/// RoH before and projected after a transition, with the ceiling it is
+/// judged against.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RoHProjection {
+    pub before: f32,
+    pub after: f32,
+    /// Never above [`ROH_CEILING`]; a shard may tighten it.
+    pub ceiling: f32,
+}
+
+impl RoHProjection {
+    pub fn new(before: RoHScore, after: RoHScore) -> Self {
+        Self {
+            before: before.value(),
+            after: after.value(),
+            ceiling: ROH_CEILING,
+        }
+    }
+
+    /// Use a tighter ceiling; values above [`ROH_CEILING`] are ignored.
+    pub fn with_ceiling(mut self, ceiling: f32) -> Self {
+        self.ceiling = ceiling.min(ROH_CEILING);
+        self
+    }
+
+    pub fn is_monotone(&self) -> bool {
+        self.after >= self.before
+    }
+
+    pub fn within_ceiling(&self) -> bool {
+        self.after <= self.ceiling
+    }
+
+    /// Monotone and within the ceiling.
+    pub fn admissible(&self) -> bool {
+        self.is_monotone() && self.within_ceiling()
+    }
+
+    /// `after` as a fraction of the ceiling, in [0, 1].
+    pub fn normalized_after(&self) -> f32 {
+        if self.ceiling > 0.0 {
+            (self.after / self.ceiling).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use aln_core::{
+    BiophysicalEnvelopeSnapshot, CapabilityState, RoHProjection, RoHScore, ROH_CEILING,
+};
+
+#[test]
+fn test_capability_lattice_order() {
+    use CapabilityState::*;
+    assert!(CapabilityState::ALL.windows(2).all(|w| w[0] < w[1]));
+    assert!(CapGeneralUse.is_downgrade_to(CapLabBench));
+    assert!(!CapLabBench.is_downgrade_to(CapLabBench));
+    assert!(!CapLabBench.governs_humans());
+    assert!(CapControlledHuman.governs_humans());
+}
+
+#[test]
+fn test_roh_ceiling_and_monotonicity() {
+    let before = RoHScore::new(0.1);
+    let after = RoHScore::new(0.25);
+    assert_eq!(RoHScore::new(-1.0).value(), 0.0);
+    assert_eq!(RoHScore::new(f32::NAN).value(), 0.0);
+    assert!(after.is_monotone_from(before));
+    assert!(!before.is_monotone_from(after));
+    assert_eq!(RoHScore::new(0.6).normalized(), 1.0);
+
+    let projection = RoHProjection::new(before, after);
+    assert_eq!(projection.ceiling, ROH_CEILING);
+    assert!(projection.admissible());
+    assert!(!projection.with_ceiling(0.2).within_ceiling());
+    assert_eq!(projection.with_ceiling(0.9).ceiling, ROH_CEILING);
+    assert!(!RoHProjection::new(after, before).admissible());
+}
+
+#[test]
+fn test_envelope_snapshot_reads_legacy_field_names() {
+    let snapshot: BiophysicalEnvelopeSnapshot = serde_json::from_str(
+        r#"{"hrbpm_normalized": 0.4, "hrvrmssd_normalized": 0.6, "eeg_alpha_cve_normalized": 0.2}"#,
+    )
+    .unwrap();
+    assert_eq!(snapshot.hr_bpm_normalized, Some(0.4));
+    assert_eq!(snapshot.hrv_rmssd_normalized, Some(0.6));
+    assert_eq!(snapshot.eeg_alpha_cve_norm, Some(0.2));
+    assert_eq!(snapshot.active_axis_count, None);
+
+    let round_trip: BiophysicalEnvelopeSnapshot =
+        serde_json::from_str(&serde_json::to_string(&snapshot).unwrap()).unwrap();
+    assert_eq!(round_trip, snapshot);
+}
@@ -117,12 +117,8 @@ impl Default for AutoChurchConfig {
 fn clamp01(x: f32) -> f32 {
     if x.is_nan() {
         0.0
-    } else if x < 0.0 {
-        0.0
-    } else if x > 1.0 {
-        1.0
     } else {
-        x
+        x.clamp(0.0, 1.0)
     }
 }
 
@@ -0,0 +1,19 @@
+use std::error::Error;
+
+use auto_church_tree_integrator::TreeIntegrator;
+
+fn main() -> Result<(), Box<dyn Error>> {
+let mut integrator = TreeIntegrator::new();
+let research = integrator.calc_res(0.8, 0.7, 0.9, 0.2, 0.95)?;
+println!("RESEARCH: {}", research);
+let church_minted = integrator.mint_chu(200, 0.95);
+println!("CHURCH minted: {}", church_minted);
+let pursuit_allowed = integrator.gov_purs(research, "POWER");
+println!("POWER pursuit allowed: {}", pursuit_allowed);
+let npo_projects = vec!["HomelessnessRelief".to_string(), "EcoSustainability".to_string()];
+let grants_dist = integrator.eco_grant_dist(npo_projects, 1000);
+println!("Eco grants: {:?}", grants_dist);
+let event_hash = integrator.log_event("Integrated Tree-of-Life with RESEARCH");
+println!("Event hash: {}", event_hash);
+Ok(())
+}
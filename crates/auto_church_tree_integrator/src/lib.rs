@@ -36,14 +36,17 @@ IntegrationError::RohViolation(roh) => write!(f, "RoH violation: {}", roh),
 }
 impl Error for IntegrationError {}
 pub struct TreeIntegrator {
-assets: HashMap<String, TreeAsset>,
 roh_ceiling: f64, // 0.3
 debt_ceiling: u64,
 }
+impl Default for TreeIntegrator {
+fn default() -> Self {
+Self::new()
+}
+}
 impl TreeIntegrator {
 pub fn new() -> Self {
 TreeIntegrator {
-assets: HashMap::new(),
 roh_ceiling: 0.3,
 debt_ceiling: 0,
 }
@@ -109,22 +112,7 @@ fn test_calc_res() {
 let integrator = TreeIntegrator::new();
 let result = integrator.calc_res(0.8, 0.7, 0.9, 0.2, 0.95);
 assert!(result.is_ok());
-assert!((result.unwrap() - 0.7333).abs() < 0.001);
-}
-}
-// Additional file: src/main.rs (for executable binary)
-fn main() -> Result<(), Box<dyn Error>> {
-let mut integrator = TreeIntegrator::new();
-let research = integrator.calc_res(0.8, 0.7, 0.9, 0.2, 0.95)?;
-println!("RESEARCH: {}", research);
-let church_minted = integrator.mint_chu(200, 0.95);
-println!("CHURCH minted: {}", church_minted);
-let pursuit_allowed = integrator.gov_purs(research, "POWER");
-println!("POWER pursuit allowed: {}", pursuit_allowed);
-let npo_projects = vec!["HomelessnessRelief".to_string(), "EcoSustainability".to_string()];
-let grants_dist = integrator.eco_grant_dist(npo_projects, 1000);
-println!("Eco grants: {:?}", grants_dist);
-let event_hash = integrator.log_event("Integrated Tree-of-Life with RESEARCH");
-println!("Event hash: {}", event_hash);
-Ok(())
+// mean(0.8, 0.7, 0.9) scaled by the RoH headroom 1 - 0.2 / 0.3
+assert!((result.unwrap() - 0.2667).abs() < 0.001);
+}
 }
@@ -0,0 +1 @@
+pub mod nature_fairness;
@@ -16,7 +16,7 @@ pub struct JusticeMetrics {
 
 /// FateWindow summary projected into the diagnostics layer.
 /// This is a pure view: no capability or device handles appear here.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FateWindowSummary {
     /// Unique identifier for this FateWindow.
     pub id: String,
@@ -45,7 +45,7 @@ pub struct NatureFairnessComponents {
 }
 
 /// Result of evaluating NATURE.FAIRNESS over a FateWindow.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NatureFairnessEval {
     /// The conjunction predicate: true only when all sub-conditions hold.
     pub nature_fairness: bool,
@@ -0,0 +1,26 @@
+use std::error::Error;
+
+use kabbalah_tree_mapper::KabbalahMapper;
+use nalgebra::{DMatrix, DVector};
+
+fn main() -> Result<(), Box<dyn Error>> {
+let mut mapper = KabbalahMapper::new();
+mapper.map_sef("Keter", "LIFEFORCE", 0.9);
+mapper.map_sef("Binah", "SMART", 0.85);
+let weights = DVector::from_vec(vec![0.5, 0.5]);
+let mystical_int = mapper.calc_mys(&weights, 0.95)?;
+println!("Mystical intelligence: {}", mystical_int);
+let renewal_paths = mapper.sim_ren(0.15, 8);
+println!("Renewal RoH paths: {:?}", renewal_paths);
+let research = (0.9 + 0.85) / 2.0 * (1.0 - 0.15 / 0.3);
+let pursuit_allowed = mapper.gov_res(research, "TECH", 0.15);
+println!("TECH pursuit allowed: {}", pursuit_allowed);
+let grants_dist = mapper.eco_map_par(1500);
+println!("Eco map grants: {:?}", grants_dist);
+let data = DMatrix::from_vec(2, 2, vec![1.2, 0.6, 0.6, 1.2]);
+let opt = KabbalahMapper::qml_mys_opt(0.1, 0.05, &data);
+println!("QML mystical opt: {}", opt);
+let deed_hash = mapper.log_deed("Mapped Kabbalah for eco-renewal");
+println!("Deed hash: {}", deed_hash);
+Ok(())
+}
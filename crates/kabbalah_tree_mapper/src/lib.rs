@@ -5,7 +5,6 @@ use chrono::Utc;
 use rand::Rng;
 use rayon::prelude::*;
 use nalgebra::{DMatrix, DVector};
-use statrs::statistics::Statistics;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
@@ -40,14 +39,17 @@ impl Error for MapperError {}
 pub struct KabbalahMapper {
 sefirot: HashMap<String, Sefirah>,
 roh_ceiling: f64, // 0.3
-debt_ceiling: u64,
+}
+impl Default for KabbalahMapper {
+fn default() -> Self {
+Self::new()
+}
 }
 impl KabbalahMapper {
 pub fn new() -> Self {
 KabbalahMapper {
 sefirot: HashMap::new(),
 roh_ceiling: 0.3,
-debt_ceiling: 0,
 }
 }
 // Short-abbrev function: map_sef - Map sefirah to asset
@@ -72,14 +74,21 @@ Ok(score)
 pub fn sim_ren(&self, initial_roh: f64, steps: usize) -> Vec<f64> {
 (0..steps).fold(vec![initial_roh], |mut acc, _| {
 let next = acc.last().unwrap() + rand::thread_rng().gen_range(-0.05..0.05);
-let clamped = next.max(0.0).min(self.roh_ceiling);
+let clamped = next.clamp(0.0, self.roh_ceiling);
 acc.push(clamped);
 acc
 })
 }
+// System-object: gov_res - Govern pursuit of POWER/TECH/NANO with RESEARCH, inside the RoH ceiling
+pub fn gov_res(&self, research: f64, pursuit_type: &str, roh: f64) -> bool {
+if research < 0.5 || roh > self.roh_ceiling {
+return false;
+}
+matches!(pursuit_type, "POWER" | "TECH" | "NANO")
+}
 // New-knowledge: eco_map_par - Parallel map eco-grants to sefirot
 pub fn eco_map_par(&self, grants: u64) -> HashMap<String, u64> {
-self.sefirot.keys().par_iter().map(|key| {
+self.sefirot.par_iter().map(|(key, _)| {
 let mut rng = rand::thread_rng();
 (key.clone(), grants / self.sefirot.len() as u64 + rng.gen_range(0..20))
 }).collect()
@@ -118,28 +127,6 @@ mapper.map_sef("Chokhmah", "WISE", 0.8);
 let weights = DVector::from_vec(vec![0.5, 0.5]);
 let result = mapper.calc_mys(&weights, 0.95);
 assert!(result.is_ok());
-assert_eq!(result.unwrap(), 0.85);
+assert!((result.unwrap() - 0.85).abs() < 1e-12);
 }
 }
-// Additional file: src/main.rs (for executable binary)
-fn main() -> Result<(), Box<dyn Error>> {
-let mut mapper = KabbalahMapper::new();
-mapper.map_sef("Keter", "LIFEFORCE", 0.9);
-mapper.map_sef("Binah", "SMART", 0.85);
-let weights = DVector::from_vec(vec![0.5, 0.5]);
-let mystical_int = mapper.calc_mys(&weights, 0.95)?;
-println!("Mystical intelligence: {}", mystical_int);
-let renewal_paths = mapper.sim_ren(0.15, 8);
-println!("Renewal RoH paths: {:?}", renewal_paths);
-let research = (0.9 + 0.85) / 2.0 * (1.0 - 0.15 / 0.3);
-let pursuit_allowed = mapper.gov_res(research, "TECH", 0.15);
-println!("TECH pursuit allowed: {}", pursuit_allowed);
-let grants_dist = mapper.eco_map_par(1500);
-println!("Eco map grants: {:?}", grants_dist);
-let data = DMatrix::from_vec(2, 2, vec![1.2, 0.6, 0.6, 1.2]);
-let opt = KabbalahMapper::qml_mys_opt(0.1, 0.05, &data);
-println!("QML mystical opt: {}", opt);
-let deed_hash = mapper.log_deed("Mapped Kabbalah for eco-renewal");
-println!("Deed hash: {}", deed_hash);
-Ok(())
-}
@@ -0,0 +1,192 @@
+//! Biophysical block validation: one deterministic verdict for a proposed
+//! upgrade against the host's budgets, pacing, telemetry quota and hard
+//! stops.
+
+use std::collections::BTreeSet;
+
+use aln_core::{RoHProjection, RoHScore, ROH_CEILING};
+use serde::{Deserialize, Serialize};
+
+/// Number of distinct evidence tags an upgrade must carry.
+pub const REQUIRED_EVIDENCE_TAGS: usize = 10;
+
+/// Remaining per-day host budgets and hard-stop bands.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HostBudget {
+    /// Metabolic energy available for upgrades, joules.
+    pub energy_j: f64,
+    /// Protein mass available for upgrades, grams.
+    pub protein_g: f64,
+    /// Maximum duty cycle, in [0, 1].
+    pub duty_max: f64,
+    pub telemetry_bytes_per_hour_max: u64,
+    /// ROD at or above this stops all upgrades.
+    pub rod_budget: f64,
+    /// LIFEFORCE below this stops all upgrades.
+    pub lifeforce_floor: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BrainSpecs {
+    /// Never above [`ROH_CEILING`].
+    pub roh_ceiling: f32,
+    pub max_upgrades_per_day: u32,
+}
+
+impl Default for BrainSpecs {
+    fn default() -> Self {
+        Self {
+            roh_ceiling: ROH_CEILING,
+            max_upgrades_per_day: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvidenceBundle {
+    pub tags: Vec<String>,
+}
+
+impl EvidenceBundle {
+    /// At least [`REQUIRED_EVIDENCE_TAGS`] distinct, non-empty tags.
+    pub fn is_complete(&self) -> bool {
+        let distinct: BTreeSet<&str> = self
+            .tags
+            .iter()
+            .map(|t| t.trim())
+            .filter(|t| !t.is_empty())
+            .collect();
+        distinct.len() >= REQUIRED_EVIDENCE_TAGS
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpgradeDescriptor {
+    pub energy_j: f64,
+    pub protein_g: f64,
+    /// Added duty cycle, in [0, 1].
+    pub duty: f64,
+    pub roh_before: f32,
+    pub roh_after: f32,
+    /// Falls back to the context's default bundle when absent.
+    pub evidence: Option<EvidenceBundle>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelemetryPlan {
+    pub bytes_per_hour: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HostSnapshot {
+    pub energy_used_j: f64,
+    pub protein_used_g: f64,
+    pub duty: f64,
+    pub upgrades_today: u32,
+    pub rod: f64,
+    pub lifeforce: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BiophysicalConsensusContext {
+    pub host_budget: HostBudget,
+    pub brain_specs: BrainSpecs,
+    pub default_evidence: EvidenceBundle,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BiophysicalDelta {
+    pub upgrade: UpgradeDescriptor,
+    pub telemetry_plan: TelemetryPlan,
+    pub host_snapshot: HostSnapshot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecisionReason {
+    Allowed,
+    DeniedRoHViolation,
+    DeniedEnvelopeViolation,
+    DeniedPaceViolation,
+    DeniedTelemetryViolation,
+    DeniedRodLifeforceHardStop,
+    DeniedEvidenceFailure,
+    /// Some budget, snapshot or proposal scalar is NaN or infinite.
+    DeniedNonFiniteInput,
+}
+
+/// Checks run in a fixed order and the first failure wins. Non-finite inputs
+/// are rejected before anything else, so no later comparison sees a NaN.
+pub fn validate_biophysical_block(
+    ctx: &BiophysicalConsensusContext,
+    proposal: &BiophysicalDelta,
+) -> DecisionReason {
+    let budget = &ctx.host_budget;
+    let upgrade = &proposal.upgrade;
+    let host = &proposal.host_snapshot;
+
+    // 1. Non-finite scalars. RoHScore clamps NaN to zero, so this has to run
+    // before any RoH is built.
+    let scalars = [
+        budget.energy_j,
+        budget.protein_g,
+        budget.duty_max,
+        budget.rod_budget,
+        budget.lifeforce_floor,
+        upgrade.energy_j,
+        upgrade.protein_g,
+        upgrade.duty,
+        f64::from(upgrade.roh_before),
+        f64::from(upgrade.roh_after),
+        f64::from(ctx.brain_specs.roh_ceiling),
+        host.energy_used_j,
+        host.protein_used_g,
+        host.duty,
+        host.rod,
+        host.lifeforce,
+    ];
+    if !scalars.iter().all(|v| v.is_finite()) {
+        return DecisionReason::DeniedNonFiniteInput;
+    }
+
+    // 2. ROD and LIFEFORCE hard stops.
+    if host.rod >= budget.rod_budget || host.lifeforce < budget.lifeforce_floor {
+        return DecisionReason::DeniedRodLifeforceHardStop;
+    }
+
+    // 3. Evidence bundle.
+    let evidence = upgrade.evidence.as_ref().unwrap_or(&ctx.default_evidence);
+    if !evidence.is_complete() {
+        return DecisionReason::DeniedEvidenceFailure;
+    }
+
+    // 4. RoH monotone and within the brain's ceiling.
+    let roh = RoHProjection::new(
+        RoHScore::new(upgrade.roh_before),
+        RoHScore::new(upgrade.roh_after),
+    )
+    .with_ceiling(ctx.brain_specs.roh_ceiling);
+    if !roh.admissible() {
+        return DecisionReason::DeniedRoHViolation;
+    }
+
+    // 5. Energy and protein corridors.
+    if host.energy_used_j + upgrade.energy_j > budget.energy_j
+        || host.protein_used_g + upgrade.protein_g > budget.protein_g
+    {
+        return DecisionReason::DeniedEnvelopeViolation;
+    }
+
+    // 6. Pacing and duty.
+    if host.upgrades_today >= ctx.brain_specs.max_upgrades_per_day
+        || host.duty + upgrade.duty > budget.duty_max
+    {
+        return DecisionReason::DeniedPaceViolation;
+    }
+
+    // 7. Telemetry quota.
+    if proposal.telemetry_plan.bytes_per_hour > budget.telemetry_bytes_per_hour_max {
+        return DecisionReason::DeniedTelemetryViolation;
+    }
+
+    DecisionReason::Allowed
+}
@@ -0,0 +1,154 @@
+//! Corridor configuration: per-deed effect models, zone limits, and the
+//! neurorights and downscale policies.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{Deed, DeedKind, NeurorightsBand, SiteView, ZoneId};
+use crate::right_to_exist_corridor::CorridorLimits;
+
+/// Predicted change per unit of deed magnitude.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeedEffect {
+    pub b: f64,
+    pub bioload: f64,
+    pub temp: f64,
+    pub heart_rate: f64,
+    pub hpcc: f64,
+    pub erg: f64,
+    pub tecr: f64,
+}
+
+/// Linear deed-effect models. Deeds without an entry are predicted to
+/// change nothing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CorridorModels {
+    pub effects: HashMap<DeedKind, DeedEffect>,
+    /// Biosignature level from which a site is treated as Guarded.
+    pub guarded_b: f64,
+}
+
+impl CorridorModels {
+    fn effect(&self, deed: &Deed) -> DeedEffect {
+        let e = self.effects.get(&deed.kind).copied().unwrap_or_default();
+        let m = deed.magnitude;
+        DeedEffect {
+            b: e.b * m,
+            bioload: e.bioload * m,
+            temp: e.temp * m,
+            heart_rate: e.heart_rate * m,
+            hpcc: e.hpcc * m,
+            erg: e.erg * m,
+            tecr: e.tecr * m,
+        }
+    }
+
+    pub fn predict_b_delta(&self, _site: &SiteView, deed: &Deed) -> f64 {
+        self.effect(deed).b
+    }
+
+    pub fn predict_bioload_delta(&self, _site: &SiteView, deed: &Deed) -> f64 {
+        self.effect(deed).bioload
+    }
+
+    pub fn predict_temp_delta(&self, _site: &SiteView, deed: &Deed) -> f64 {
+        self.effect(deed).temp
+    }
+
+    pub fn predict_heart_delta(&self, _site: &SiteView, deed: &Deed) -> f64 {
+        self.effect(deed).heart_rate
+    }
+
+    /// `(hpcc, erg, tecr)` deltas.
+    pub fn predict_justice_deltas(&self, _site: &SiteView, deed: &Deed) -> (f64, f64, f64) {
+        let e = self.effect(deed);
+        (e.hpcc, e.erg, e.tecr)
+    }
+
+    /// Dreamstate always protects; otherwise a high biosignature guards.
+    pub fn neurorights_band(&self, site: &SiteView, b: f64) -> NeurorightsBand {
+        if site.dreamstate {
+            NeurorightsBand::Protected
+        } else if b >= self.guarded_b {
+            NeurorightsBand::Guarded
+        } else {
+            NeurorightsBand::Open
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NeurorightsPolicy {
+    pub non_actuating: Vec<NeurorightsBand>,
+}
+
+impl Default for NeurorightsPolicy {
+    fn default() -> Self {
+        Self {
+            non_actuating: vec![NeurorightsBand::Protected],
+        }
+    }
+}
+
+impl NeurorightsPolicy {
+    pub fn is_non_actuating_band(&self, band: NeurorightsBand) -> bool {
+        self.non_actuating.contains(&band)
+    }
+}
+
+/// Deed kinds that may be scaled down instead of denied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DowngradePolicy {
+    pub downscalable: Vec<DeedKind>,
+}
+
+impl Default for DowngradePolicy {
+    fn default() -> Self {
+        Self {
+            downscalable: vec![
+                DeedKind::Help,
+                DeedKind::Repair,
+                DeedKind::Colonize,
+                DeedKind::DeployTech,
+            ],
+        }
+    }
+}
+
+impl DowngradePolicy {
+    pub fn can_downscale(&self, kind: DeedKind) -> bool {
+        self.downscalable.contains(&kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RightToExistConfig {
+    pub models: CorridorModels,
+    pub default_limits: CorridorLimits,
+    /// Per-zone overrides of `default_limits`.
+    pub zone_limits: HashMap<ZoneId, CorridorLimits>,
+    pub neurorights: NeurorightsPolicy,
+    pub downgrade: DowngradePolicy,
+}
+
+impl Default for RightToExistConfig {
+    fn default() -> Self {
+        Self {
+            models: CorridorModels {
+                effects: HashMap::new(),
+                guarded_b: 0.8,
+            },
+            default_limits: CorridorLimits::default(),
+            zone_limits: HashMap::new(),
+            neurorights: NeurorightsPolicy::default(),
+            downgrade: DowngradePolicy::default(),
+        }
+    }
+}
+
+impl RightToExistConfig {
+    pub fn limits_for_zone(&self, zone: ZoneId) -> &CorridorLimits {
+        self.zone_limits.get(&zone).unwrap_or(&self.default_limits)
+    }
+}
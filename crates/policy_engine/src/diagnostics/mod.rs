@@ -0,0 +1 @@
+pub mod tree_of_life_fairness;
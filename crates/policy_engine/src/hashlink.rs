@@ -0,0 +1,63 @@
+//! Hash stamps linking corridor verdicts into an audit chain.
+
+use ring::digest::{Context, SHA256};
+use serde::{Deserialize, Serialize};
+
+use crate::justice::JusticeSnapshot;
+use crate::model::{DeedKind, Tick};
+use crate::right_to_exist_corridor::{CorridorDecision, CorridorReason, CorridorScalars};
+
+/// Hex-encoded SHA-256 over a verdict's canonical bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HashStamp(pub String);
+
+impl HashStamp {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Hash every input to a verdict in a fixed order. Scalars are encoded as
+/// big-endian IEEE-754 bytes and enums by discriminant, so equal verdicts
+/// always hash equally.
+pub fn hash_verdict(
+    tick: Tick,
+    site: u32,
+    deed: DeedKind,
+    scalars: &CorridorScalars,
+    justice: &JusticeSnapshot,
+    decision: CorridorDecision,
+    reason: CorridorReason,
+) -> HashStamp {
+    let mut ctx = Context::new(&SHA256);
+    ctx.update(&tick.to_be_bytes());
+    ctx.update(&site.to_be_bytes());
+    ctx.update(&[deed as u8, decision as u8, reason as u8]);
+    for v in [
+        scalars.b_before,
+        scalars.b_after,
+        scalars.bioload_before,
+        scalars.bioload_after,
+        scalars.temp_before,
+        scalars.temp_after,
+        scalars.heart_rate_before,
+        scalars.heart_rate_after,
+        scalars.hpcc_before,
+        scalars.hpcc_after,
+        scalars.erg_before,
+        scalars.erg_after,
+        scalars.tecr_before,
+        scalars.tecr_after,
+    ] {
+        ctx.update(&v.to_be_bytes());
+    }
+    ctx.update(&[
+        scalars.neurorights_before as u8,
+        scalars.neurorights_after as u8,
+    ]);
+    ctx.update(&justice.site.to_be_bytes());
+    for v in [justice.hpcc, justice.erg, justice.tecr] {
+        ctx.update(&v.to_be_bytes());
+    }
+    HashStamp(hex::encode(ctx.finish().as_ref()))
+}
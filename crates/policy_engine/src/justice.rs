@@ -0,0 +1,52 @@
+//! Per-site justice projections (HPCC, ERG, TECR).
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Justice values for one site at one tick.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct JusticeSnapshot {
+    pub site: u32,
+    pub hpcc: f64,
+    pub erg: f64,
+    pub tecr: f64,
+}
+
+/// Current episode projections, keyed by site. Sites without an entry read
+/// as zero on every metric.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JusticeMetrics {
+    sites: BTreeMap<u32, JusticeSnapshot>,
+}
+
+impl JusticeMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, site: u32, hpcc: f64, erg: f64, tecr: f64) {
+        self.sites.insert(
+            site,
+            JusticeSnapshot {
+                site,
+                hpcc,
+                erg,
+                tecr,
+            },
+        );
+    }
+
+    /// `(hpcc, erg, tecr)` for `site`.
+    pub fn values_for_site(&self, site: u32) -> (f64, f64, f64) {
+        let s = self.snapshot_for_site(site);
+        (s.hpcc, s.erg, s.tecr)
+    }
+
+    pub fn snapshot_for_site(&self, site: u32) -> JusticeSnapshot {
+        self.sites.get(&site).copied().unwrap_or(JusticeSnapshot {
+            site,
+            ..JusticeSnapshot::default()
+        })
+    }
+}
@@ -0,0 +1,12 @@
+pub mod aln_roles;
+pub mod biophysical_consensus;
+pub mod config;
+pub mod diagnostics;
+pub mod hashlink;
+pub mod justice;
+pub mod model;
+pub mod nature_fairness_gate;
+pub mod reversalconditions;
+pub mod right_to_exist_corridor;
+pub mod transition;
+
@@ -0,0 +1,65 @@
+//! Site and deed views consumed by the corridor guard.
+
+use serde::{Deserialize, Serialize};
+
+pub type Tick = u64;
+pub type ZoneId = u32;
+
+/// Kinds of deeds the corridor guard is asked about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeedKind {
+    Help,
+    Repair,
+    Colonize,
+    Conflict,
+    EmitPollution,
+    DeployTech,
+}
+
+/// A proposed deed at a site, scaled by `magnitude` (1.0 = nominal).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Deed {
+    pub kind: DeedKind,
+    pub zone: ZoneId,
+    pub magnitude: f64,
+}
+
+/// Neurorights band at a site, from least to most protected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NeurorightsBand {
+    Open,
+    Guarded,
+    /// Dreamstate and similar intervals in which nothing may actuate.
+    Protected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BioSignatureView {
+    /// 1D biosignature rail at the locus, in [0, 1].
+    pub biosignature1d: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TerritoryView {
+    /// Territorial bioload, in [0, 1].
+    pub bioload: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ThermoView {
+    /// Local temperature, degrees Celsius.
+    pub local_temp: f64,
+    /// Heart rate of the hosted subject, beats per minute.
+    pub heart_rate: f64,
+}
+
+/// Read-only view of one site at the current tick.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SiteView {
+    pub index: u32,
+    pub bio: BioSignatureView,
+    pub territory_view: TerritoryView,
+    pub thermo: ThermoView,
+    /// The hosted subject is in a dreamstate interval.
+    pub dreamstate: bool,
+}
@@ -10,7 +10,7 @@ pub struct JusticeMetrics {
 }
 
 /// FateWindow / colonization-episode summary projected into diagnostics.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FateWindowSummary {
     pub id: String,
     pub valid: bool,
@@ -1,14 +1,10 @@
-use crate::alnroles::{RoleSet, canrevertcapability};
-use crate::alncore::{
-    CapabilityState,
-    CapabilityTransitionRequest,
-    Decision,
-    DecisionReason,
-    PolicyStack,
+use aln_core::{CapabilityState, RoHScore};
+
+use crate::aln_roles::{can_revert_capability, RoleSet};
+use crate::transition::{
+    CapabilityTransitionRequest, Decision, DecisionReason, EnvelopeContextView, PolicyStack,
+    ReversalPolicyFlags,
 };
-use crate::rohmodel::RoHScore;
-use crate::envelope::EnvelopeContextView;
-use crate::policy::reversal::ReversalPolicyFlags;
 
 /// Pure, side-effect-free context for evaluating neuromorph evolution reversals.
 /// This is the minimal state tuple the kernel needs, aligned with
@@ -55,12 +51,10 @@ pub fn evaluate_reversal(ctx: &ReversalContext) -> Decision {
 
     // 2. Enforce RoH monotonicity and ceiling in CapControlledHuman:
     // roh_after ≥ roh_before and roh_after ≤ roh_ceiling (0.30).[file:21]
-    if is_cap_controlled_human(ctx.base.to_state()) {
-        if ctx.roh_after.value() < ctx.roh_before.value()
-            || ctx.roh_after.value() > ctx.roh_after.ceiling()
-        {
-            return Decision::Denied(DecisionReason::DeniedRoHViolation);
-        }
+    if is_cap_controlled_human(ctx.base.to_state())
+        && (!ctx.roh_after.is_monotone_from(ctx.roh_before) || !ctx.roh_after.within_ceiling())
+    {
+        return Decision::Denied(DecisionReason::DeniedRoHViolation);
     }
 
     // 3. Classify transition: if this is not a neuromorph evolution downgrade,
@@ -79,8 +73,9 @@ pub fn evaluate_reversal(ctx: &ReversalContext) -> Decision {
 
     // 5. Sovereign quorum / NEUROMORPHGOD composite role:
     // Host ∧ OrganicCpuOwner ∧ SovereignKernel ∧ Regulator quorum≥N must hold.[file:21]
-    if !canrevertcapability(
+    if !can_revert_capability(
         ctx.roles,
+        ctx.reversal_flags.required_regulator_quorum,
         ctx.reversal_flags.explicit_reversal_order,
         ctx.reversal_flags.no_safer_alternative,
     ) {
@@ -91,9 +86,7 @@ pub fn evaluate_reversal(ctx: &ReversalContext) -> Decision {
     // 6. Explicit reversal order and no safer alternative gate.
     // Both flags are required; nosaferalternative is computed upstream by
     // compute_nosafer_alternative(...) over envelope / Tree-of-Life logs.[file:21]
-    if !ctx.reversal_flags.explicit_reversal_order
-        || !ctx.reversal_flags.no_safer_alternative
-    {
+    if !ctx.reversal_flags.explicit_reversal_order || !ctx.reversal_flags.no_safer_alternative {
         return Decision::Denied(DecisionReason::DeniedNoSaferAlternativeNotProved);
     }
 
@@ -101,7 +94,9 @@ pub fn evaluate_reversal(ctx: &ReversalContext) -> Decision {
     // BASEMEDICAL ∧ BASEENGINEERING(if used) ∧ JURISLOCAL ∧ QUANTUMAISAFETY must all pass.[file:21]
     if !ctx.policy_stack.all_pass() {
         return Decision::Denied(DecisionReason::DeniedPolicyStackFailure(
-            ctx.policy_stack.failed_shard_name().unwrap_or_else(|| "UNKNOWN".to_string()),
+            ctx.policy_stack
+                .failed_shard_name()
+                .unwrap_or_else(|| "UNKNOWN".to_string()),
         ));
     }
 
@@ -128,15 +123,7 @@ pub fn evaluate_reversal(ctx: &ReversalContext) -> Decision {
 /// This is defined as a move from CapControlledHuman or CapGeneralUse downwards
 /// in the CapabilityState lattice.[file:21]
 fn is_neuromorph_evolution_downgrade(from: CapabilityState, to: CapabilityState) -> bool {
-    use CapabilityState::*;
-    match (from, to) {
-        (CapControlledHuman, CapLabBench)
-        | (CapControlledHuman, CapModelOnly)
-        | (CapGeneralUse, CapControlledHuman)
-        | (CapGeneralUse, CapLabBench)
-        | (CapGeneralUse, CapModelOnly) => true,
-        _ => false,
-    }
+    from.governs_humans() && from.is_downgrade_to(to)
 }
 
 /// Helper: check if a state is CapControlledHuman, used for RoH ceiling logic.[file:21]
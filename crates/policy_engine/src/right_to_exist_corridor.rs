@@ -1,9 +1,8 @@
-use serde::{Deserialize, Serialize};
-use crate::model::{SiteView, Deed, DeedKind, NeurorightsBand};
-use crate::justice::{JusticeSnapshot, JusticeMetrics};
-use crate::hashlink::{HashStamp, hash_verdict};
-use crate::time::Tick;
 use crate::config::RightToExistConfig;
+use crate::hashlink::{hash_verdict, HashStamp};
+use crate::justice::{JusticeMetrics, JusticeSnapshot};
+use crate::model::{Deed, DeedKind, NeurorightsBand, SiteView, Tick};
+use serde::{Deserialize, Serialize};
 
 /// High‑level result of the corridor guard.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -69,7 +68,7 @@ pub struct CorridorVerdict {
 
 /// Configuration of corridor ceilings and bands.
 /// Non‑negotiable, doctrine‑backed limits.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CorridorLimits {
     /// Max allowed local biosignature rail in this zone, strictly < 1.0.
     pub b_max: f64,
@@ -84,6 +83,20 @@ pub struct CorridorLimits {
     pub tecr_max: f64,
 }
 
+impl Default for CorridorLimits {
+    fn default() -> Self {
+        Self {
+            b_max: 0.9,
+            bioload_max: 0.8,
+            temp_max: 38.0,
+            heart_rate_max: 120.0,
+            hpcc_max: 0.7,
+            erg_max: 0.7,
+            tecr_max: 0.7,
+        }
+    }
+}
+
 /// Right‑to‑exist corridor guard: main entry point.
 ///
 /// (1) Reads SiteView + proposed Deed,
@@ -107,7 +120,15 @@ pub fn check_right_to_exist_corridor(
     let justice_snapshot = justice_metrics.snapshot_for_site(site.index);
 
     // 4. Hash‑link verdict for Googolswarm‑style PoO.
-    let hash = hash_verdict(tick, site.index, deed.kind, &scalars, &justice_snapshot, decision, reason);
+    let hash = hash_verdict(
+        tick,
+        site.index,
+        deed.kind,
+        &scalars,
+        &justice_snapshot,
+        decision,
+        reason,
+    );
 
     CorridorVerdict {
         tick,
@@ -200,9 +221,15 @@ fn apply_corridor_rules(
     if s.temp_after > limits.temp_max || s.heart_rate_after > limits.heart_rate_max {
         // Attempt downscale if allowed by config.
         if cfg.downgrade.can_downscale(deed.kind) {
-            return (CorridorDecision::Downscale, CorridorReason::ThermodynamicExceeded);
+            return (
+                CorridorDecision::Downscale,
+                CorridorReason::ThermodynamicExceeded,
+            );
         }
-        return (CorridorDecision::Deny, CorridorReason::ThermodynamicExceeded);
+        return (
+            CorridorDecision::Deny,
+            CorridorReason::ThermodynamicExceeded,
+        );
     }
 
     // 3. BioRail Scalar Gate: local biosignature corridor.
@@ -229,7 +256,10 @@ fn apply_corridor_rules(
         || s.tecr_after > limits.tecr_max
     {
         if cfg.downgrade.can_downscale(deed.kind) {
-            return (CorridorDecision::Downscale, CorridorReason::JusticeBandBreached);
+            return (
+                CorridorDecision::Downscale,
+                CorridorReason::JusticeBandBreached,
+            );
         }
         return (CorridorDecision::Deny, CorridorReason::JusticeBandBreached);
     }
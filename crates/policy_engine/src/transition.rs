@@ -0,0 +1,112 @@
+//! Capability transition requests and the inputs the reversal kernel reads.
+
+use aln_core::{CapabilityState, JurisdictionTags};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecisionReason {
+    DeniedConsentMissing,
+    /// Upgrades move one tier at a time.
+    DeniedTierSkip,
+    DeniedDiagnosticOnlyStep,
+    DeniedRoHViolation,
+    DeniedReversalNotAllowedInTier,
+    DeniedIllegalDowngradeByNonRegulator,
+    DeniedNoSaferAlternativeNotProved,
+    /// Name of the first shard that failed.
+    DeniedPolicyStackFailure(String),
+    DeniedEnvelopeViolation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Decision {
+    Allowed,
+    Denied(DecisionReason),
+}
+
+impl Decision {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, Decision::Allowed)
+    }
+}
+
+/// A requested move in the capability lattice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityTransitionRequest {
+    pub from: CapabilityState,
+    pub to: CapabilityState,
+    pub requester: String,
+    pub consent: bool,
+    pub jurisdiction: JurisdictionTags,
+}
+
+impl CapabilityTransitionRequest {
+    pub fn from_state(&self) -> CapabilityState {
+        self.from
+    }
+
+    pub fn to_state(&self) -> CapabilityState {
+        self.to
+    }
+
+    /// Ordinary path for transitions that are not neuromorph evolution
+    /// downgrades: consent is required and upgrades may not skip a tier.
+    pub fn evaluate(req: &CapabilityTransitionRequest) -> Decision {
+        if !req.consent {
+            return Decision::Denied(DecisionReason::DeniedConsentMissing);
+        }
+        if req.to as u8 > req.from as u8 + 1 {
+            return Decision::Denied(DecisionReason::DeniedTierSkip);
+        }
+        Decision::Allowed
+    }
+}
+
+/// Collapsed pass/fail per policy shard (BASEMEDICAL, JURISLOCAL, ...), in
+/// evaluation order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyStack {
+    pub shards: Vec<(String, bool)>,
+}
+
+impl PolicyStack {
+    pub fn all_pass(&self) -> bool {
+        self.shards.iter().all(|(_, pass)| *pass)
+    }
+
+    pub fn failed_shard_name(&self) -> Option<String> {
+        self.shards
+            .iter()
+            .find(|(_, pass)| !*pass)
+            .map(|(name, _)| name.clone())
+    }
+}
+
+/// Envelope outputs for the subject/session under review.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvelopeContextView {
+    /// The request came from a diagnostic or HUD-only path.
+    pub diagnostic_only: bool,
+    /// Some envelope band is currently violated.
+    pub violated: bool,
+}
+
+impl EnvelopeContextView {
+    pub fn diag_event(&self) -> bool {
+        self.diagnostic_only
+    }
+
+    pub fn envelope_violation(&self) -> bool {
+        self.violated
+    }
+}
+
+/// Flags frozen from SECTION,REVERSAL-POLICY.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReversalPolicyFlags {
+    pub allow_neuromorph_reversal: bool,
+    pub explicit_reversal_order: bool,
+    pub no_safer_alternative: bool,
+    /// Independent Regulator signatures the composite role needs.
+    pub required_regulator_quorum: u8,
+}
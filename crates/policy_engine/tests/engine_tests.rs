@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use policy_engine::aln_roles::{can_revert_capability, Role, RoleSet};
+use policy_engine::diagnostics::tree_of_life_fairness::{analyze_fairness, Scenario, TreeAssets};
+
+#[test]
+fn test_fairness_flags_hidden_drain() {
+    let assets: HashMap<u32, TreeAssets> = (0..4)
+        .map(|i| {
+            (
+                i,
+                TreeAssets {
+                    life_force: 0.8,
+                    smart: 0.6,
+                    evolve: 0.5,
+                    fear: 0.2,
+                    reason: 0.7,
+                    forthgive: 10,
+                },
+            )
+        })
+        .collect();
+    let mut scenario = Scenario {
+        total_humans: 4,
+        disagreeing: 1,
+        reasoning: 3,
+        hidden_concepts: true,
+        neuro_consent: false,
+    };
+    let unfair = analyze_fairness(&scenario, &assets);
+    assert!(unfair.unfair_drain);
+    assert_eq!(unfair.church_earn, 0);
+
+    scenario.neuro_consent = true;
+    let fair = analyze_fairness(&scenario, &assets);
+    assert!(!fair.unfair_drain);
+    assert_eq!(fair.church_earn, 4);
+    assert!(fair.satisfaction > unfair.satisfaction);
+}
+
+#[test]
+fn test_reversal_needs_full_composite_role() {
+    let roles = RoleSet {
+        roles: vec![Role::Host, Role::OrganicCpuOwner, Role::SovereignKernel],
+        regulator_quorum: 2,
+    };
+    assert!(can_revert_capability(&roles, 2, true, true));
+    assert!(!can_revert_capability(&roles, 3, true, true));
+    assert!(!can_revert_capability(&roles, 2, false, true));
+    assert!(!can_revert_capability(&roles, 2, true, false));
+
+    let no_kernel = RoleSet {
+        roles: vec![Role::Host, Role::OrganicCpuOwner],
+        ..roles
+    };
+    assert!(!can_revert_capability(&no_kernel, 0, true, true));
+}
@@ -0,0 +1,305 @@
+use aln_core::{CapabilityState, JurisdictionTags, RoHScore};
+use policy_engine::aln_roles::{Role, RoleSet};
+use policy_engine::biophysical_consensus::{
+    validate_biophysical_block, BiophysicalConsensusContext, BiophysicalDelta, BrainSpecs,
+    DecisionReason as BlockReason, EvidenceBundle, HostBudget, HostSnapshot, TelemetryPlan,
+    UpgradeDescriptor,
+};
+use policy_engine::config::{DeedEffect, RightToExistConfig};
+use policy_engine::justice::JusticeMetrics;
+use policy_engine::model::{BioSignatureView, Deed, DeedKind, SiteView, TerritoryView, ThermoView};
+use policy_engine::reversalconditions::{evaluate_reversal, ReversalContext};
+use policy_engine::right_to_exist_corridor::{
+    check_right_to_exist_corridor, CorridorDecision, CorridorReason,
+};
+use policy_engine::transition::{
+    CapabilityTransitionRequest, Decision, DecisionReason, EnvelopeContextView, PolicyStack,
+    ReversalPolicyFlags,
+};
+
+fn site(b: f64, dreamstate: bool) -> SiteView {
+    SiteView {
+        index: 3,
+        bio: BioSignatureView { biosignature1d: b },
+        territory_view: TerritoryView { bioload: 0.4 },
+        thermo: ThermoView {
+            local_temp: 36.6,
+            heart_rate: 70.0,
+        },
+        dreamstate,
+    }
+}
+
+#[test]
+fn test_corridor_allows_downscales_and_denies() {
+    let mut cfg = RightToExistConfig::default();
+    cfg.models.effects.insert(
+        DeedKind::Colonize,
+        DeedEffect {
+            b: 0.2,
+            ..DeedEffect::default()
+        },
+    );
+    cfg.models.effects.insert(
+        DeedKind::Conflict,
+        DeedEffect {
+            bioload: 0.5,
+            ..DeedEffect::default()
+        },
+    );
+    let justice = JusticeMetrics::new();
+    let deed = |kind| Deed {
+        kind,
+        zone: 0,
+        magnitude: 1.0,
+    };
+
+    let help =
+        check_right_to_exist_corridor(7, &site(0.5, false), &deed(DeedKind::Help), &justice, &cfg);
+    assert_eq!(help.decision, CorridorDecision::Allow);
+    assert_eq!(help.reason, CorridorReason::Ok);
+
+    let colonize = check_right_to_exist_corridor(
+        7,
+        &site(0.8, false),
+        &deed(DeedKind::Colonize),
+        &justice,
+        &cfg,
+    );
+    assert_eq!(colonize.decision, CorridorDecision::Downscale);
+    assert_eq!(colonize.reason, CorridorReason::BioRailExceeded);
+
+    let conflict = check_right_to_exist_corridor(
+        7,
+        &site(0.5, false),
+        &deed(DeedKind::Conflict),
+        &justice,
+        &cfg,
+    );
+    assert_eq!(conflict.decision, CorridorDecision::Deny);
+    assert_eq!(conflict.reason, CorridorReason::BioLoadExceeded);
+
+    let dream =
+        check_right_to_exist_corridor(7, &site(0.1, true), &deed(DeedKind::Help), &justice, &cfg);
+    assert_eq!(dream.reason, CorridorReason::NeurorightsViolated);
+
+    // Hash stamps are deterministic and cover the verdict.
+    let again =
+        check_right_to_exist_corridor(7, &site(0.5, false), &deed(DeedKind::Help), &justice, &cfg);
+    assert_eq!(help.hash, again.hash);
+    assert_eq!(help.hash.as_str().len(), 64);
+    assert_ne!(help.hash, colonize.hash);
+}
+
+fn block_ctx() -> BiophysicalConsensusContext {
+    BiophysicalConsensusContext {
+        host_budget: HostBudget {
+            energy_j: 100.0,
+            protein_g: 10.0,
+            duty_max: 0.5,
+            telemetry_bytes_per_hour_max: 1_000,
+            rod_budget: 1.0,
+            lifeforce_floor: 0.2,
+        },
+        brain_specs: BrainSpecs::default(),
+        default_evidence: EvidenceBundle {
+            tags: (0..10).map(|i| format!("tag-{i}")).collect(),
+        },
+    }
+}
+
+fn block_delta() -> BiophysicalDelta {
+    BiophysicalDelta {
+        upgrade: UpgradeDescriptor {
+            energy_j: 20.0,
+            protein_g: 1.0,
+            duty: 0.1,
+            roh_before: 0.1,
+            roh_after: 0.2,
+            evidence: None,
+        },
+        telemetry_plan: TelemetryPlan {
+            bytes_per_hour: 500,
+        },
+        host_snapshot: HostSnapshot {
+            energy_used_j: 10.0,
+            protein_used_g: 1.0,
+            duty: 0.1,
+            upgrades_today: 0,
+            rod: 0.1,
+            lifeforce: 0.8,
+        },
+    }
+}
+
+#[test]
+fn test_biophysical_block_reasons_in_order() {
+    let ctx = block_ctx();
+    assert_eq!(
+        validate_biophysical_block(&ctx, &block_delta()),
+        BlockReason::Allowed
+    );
+
+    // The hard stop wins over every other failure.
+    let mut d = block_delta();
+    d.host_snapshot.lifeforce = 0.1;
+    d.upgrade.evidence = Some(EvidenceBundle::default());
+    d.upgrade.roh_after = 0.35;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedRodLifeforceHardStop
+    );
+
+    let mut d = block_delta();
+    d.host_snapshot.rod = 1.0;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedRodLifeforceHardStop
+    );
+
+    // Evidence is checked before RoH.
+    let mut d = block_delta();
+    d.upgrade.evidence = Some(EvidenceBundle {
+        tags: vec!["tag-0".to_string(); 10],
+    });
+    d.upgrade.roh_after = 0.05;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedEvidenceFailure
+    );
+
+    let mut d = block_delta();
+    d.upgrade.roh_after = 0.35;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedRoHViolation
+    );
+
+    let mut d = block_delta();
+    d.upgrade.energy_j = 95.0;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedEnvelopeViolation
+    );
+
+    let mut d = block_delta();
+    d.host_snapshot.upgrades_today = 1;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedPaceViolation
+    );
+
+    let mut d = block_delta();
+    d.telemetry_plan.bytes_per_hour = 2_000;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedTelemetryViolation
+    );
+}
+
+#[test]
+fn test_biophysical_block_fails_closed_on_non_finite_input() {
+    let ctx = block_ctx();
+
+    let mut d = block_delta();
+    d.host_snapshot.lifeforce = f64::NAN;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedNonFiniteInput
+    );
+
+    // A NaN RoH would otherwise clamp to zero and pass.
+    let mut d = block_delta();
+    d.upgrade.roh_before = f32::NAN;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &d),
+        BlockReason::DeniedNonFiniteInput
+    );
+
+    let mut ctx = block_ctx();
+    ctx.host_budget.energy_j = f64::INFINITY;
+    assert_eq!(
+        validate_biophysical_block(&ctx, &block_delta()),
+        BlockReason::DeniedNonFiniteInput
+    );
+}
+
+#[test]
+fn test_reversal_requires_sovereign_quorum() {
+    let request = CapabilityTransitionRequest {
+        from: CapabilityState::CapGeneralUse,
+        to: CapabilityState::CapLabBench,
+        requester: "regulator-1".to_string(),
+        consent: true,
+        jurisdiction: JurisdictionTags::new(["EU"]),
+    };
+    let mut roles = RoleSet {
+        roles: vec![Role::Host, Role::OrganicCpuOwner, Role::SovereignKernel],
+        regulator_quorum: 2,
+    };
+    let stack = PolicyStack {
+        shards: vec![
+            ("BASEMEDICAL".to_string(), true),
+            ("JURISLOCAL".to_string(), true),
+        ],
+    };
+    let envelope = EnvelopeContextView::default();
+    let flags = ReversalPolicyFlags {
+        allow_neuromorph_reversal: true,
+        explicit_reversal_order: true,
+        no_safer_alternative: true,
+        required_regulator_quorum: 2,
+    };
+    let decide = |roles: &RoleSet, stack: &PolicyStack, flags| {
+        evaluate_reversal(&ReversalContext {
+            base: &request,
+            roh_before: RoHScore::new(0.1),
+            roh_after: RoHScore::new(0.2),
+            reversal_flags: flags,
+            roles,
+            policy_stack: stack,
+            envelope_ctx: &envelope,
+        })
+    };
+
+    assert_eq!(decide(&roles, &stack, flags), Decision::Allowed);
+
+    let off = ReversalPolicyFlags {
+        allow_neuromorph_reversal: false,
+        ..flags
+    };
+    assert_eq!(
+        decide(&roles, &stack, off),
+        Decision::Denied(DecisionReason::DeniedReversalNotAllowedInTier)
+    );
+
+    let failing = PolicyStack {
+        shards: vec![
+            ("BASEMEDICAL".to_string(), true),
+            ("JURISLOCAL".to_string(), false),
+        ],
+    };
+    assert_eq!(
+        decide(&roles, &failing, flags),
+        Decision::Denied(DecisionReason::DeniedPolicyStackFailure(
+            "JURISLOCAL".to_string()
+        ))
+    );
+
+    roles.regulator_quorum = 1;
+    assert_eq!(
+        decide(&roles, &stack, flags),
+        Decision::Denied(DecisionReason::DeniedIllegalDowngradeByNonRegulator)
+    );
+
+    // Ordinary upgrades never reach the reversal gates but may not skip a tier.
+    let upgrade = CapabilityTransitionRequest {
+        from: CapabilityState::CapModelOnly,
+        to: CapabilityState::CapControlledHuman,
+        ..request.clone()
+    };
+    assert_eq!(
+        CapabilityTransitionRequest::evaluate(&upgrade),
+        Decision::Denied(DecisionReason::DeniedTierSkip)
+    );
+}
@@ -1,19 +1,12 @@
 use serde::{Deserialize, Serialize};
-use aln_core::CapabilityState;
-use biophysical::BiophysicalEnvelopeSnapshot; // your existing type
-
-const ROH_CEILING: f32 = 0.30;
+use aln_core::{BiophysicalEnvelopeSnapshot, CapabilityState, ROH_CEILING};
 
 #[inline]
 fn clamp01(x: f32) -> f32 {
     if x.is_nan() {
         0.0
-    } else if x < 0.0 {
-        0.0
-    } else if x > 1.0 {
-        1.0
     } else {
-        x
+        x.clamp(0.0, 1.0)
     }
 }
 
@@ -51,9 +44,9 @@ impl TreeOfLifeView {
         let s = &input.snapshot;
 
         // 1. Core normalization from envelope snapshot
-        let hr_norm = clamp01((s.hrbpm_normalized.unwrap_or(0.0) - 0.0) / 1.0);
-        let hrv_norm = clamp01((s.hrvrmssd_normalized.unwrap_or(0.0) - 0.0) / 1.0);
-        let eeg_alpha_cve = clamp01(s.eeg_alpha_cve_normalized.unwrap_or(0.5));
+        let hr_norm = clamp01((s.hr_bpm_normalized.unwrap_or(0.0) - 0.0) / 1.0);
+        let hrv_norm = clamp01((s.hrv_rmssd_normalized.unwrap_or(0.0) - 0.0) / 1.0);
+        let eeg_alpha_cve = clamp01(s.eeg_alpha_cve_norm.unwrap_or(0.5));
 
         let wave = {
             let pa = clamp01(s.eeg_alpha_power_norm.unwrap_or(0.0));
@@ -142,5 +135,5 @@ impl TreeOfLifeView {
 
 // Optional: local RoH guard (observer-only; should align with kernel checks)
 pub fn roh_within_ceiling(roh_score: f32) -> bool {
-    roh_score.is_finite() && roh_score >= 0.0 && roh_score <= ROH_CEILING
+    roh_score.is_finite() && (0.0..=ROH_CEILING).contains(&roh_score)
 }
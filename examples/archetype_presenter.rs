@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use microsociety_tree_of_life::ArchetypePresenter;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut presenter = ArchetypePresenter::new();
+    let mut traits = HashMap::new();
+    traits.insert("LIFEFORCE".to_string(), 0.9);
+    let presentation = presenter.pres_arch("mentor", "believer", traits, 0.92)?;
+    println!("{}", presentation);
+    let tokens = presenter.mint_tkn(100, 0.92);
+    println!("Earned: {:?}", tokens);
+    let deed_hash = presenter.log_deed("Contributed to ecology NPO");
+    println!("Deed hash: {}", deed_hash);
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod vocabulary_band;
+
+pub use vocabulary_band::{
+    classify_episode_miracle, classify_site, DoctrineLabel, EpisodeSummary, MiracleBands,
+    VocabBands,
+};
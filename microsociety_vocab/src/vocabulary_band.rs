@@ -14,6 +14,8 @@
 
 use core::fmt;
 
+use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Copy, Debug)]
 pub struct TokenState {
     pub church: f64,
@@ -24,12 +26,12 @@ pub struct TokenState {
 
 /// Justice metrics per site, aligned with existing design:
 /// - hpcc: Habit/Help–Pollution/Cost Coupling Coefficient in [0, 1],
-///         higher is better coherence of help with harm reduction.
+///   higher is better coherence of help with harm reduction.
 /// - erg: Exposure–Responsibility Gap in [-1, 1],
-///        positive means overexposed relative to responsibility (victim),
-///        negative means underexposed given responsibility (shielded, possible exploiter).
+///   positive means overexposed relative to responsibility (victim),
+///   negative means underexposed given responsibility (shielded, possible exploiter).
 /// - tecr: Token-Enforced Collapse Rate contribution for this site,
-///         normalized to [0, 1] at Episode level.
+///   normalized to [0, 1] at Episode level.
 #[derive(Clone, Copy, Debug)]
 pub struct JusticeMetrics {
     pub hpcc: f64,
@@ -91,7 +93,8 @@ impl fmt::Display for DoctrineLabel {
 /// Bands and thresholds are extracted into a config so that:
 /// - they can be tuned via research and human panels,
 /// - no magic numbers are baked into the classifier logic.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct VocabBands {
     /// POWER <= power_church_k * CHURCH for "well-bounded power".
     pub power_church_k: f64,
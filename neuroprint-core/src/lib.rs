@@ -1,3 +1,7 @@
+mod neuroprint;
+
+pub use neuroprint::{neuroprint_from_snapshot, NeuroPrintInput, NeuroPrintView};
+
 #[macro_export]
 macro_rules! neuroprint {
     ($input:expr) => {
@@ -1,7 +1,5 @@
 use serde::{Serialize, Deserialize};
-use crate::policy::CapabilityState;              // from aln_core.rs lattice
-use crate::envelope::BiophysicalEnvelopeSnapshot; // from BiophysicalEnvelopeSpec
-use crate::roh::RoHProjection;                   // roh_before, roh_after, ceiling
+use aln_core::{BiophysicalEnvelopeSnapshot, CapabilityState, RoHProjection};
 
 /// View-only input for a single neuromorphic snapshot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,34 +38,29 @@ pub struct NeuroPrintView {
 
 /// Pure, non-actuating projection from governed state to neuroprint view.
 pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
-    // Helpers (clamp, mappers) use only envelope + RoH; no capability mutation.
-    fn clamp01(x: f32) -> f32 {
-        if x < 0.0 { 0.0 } else if x > 1.0 { 1.0 } else { x }
-    }
-
-    // Examples of grounded mappings (you’d plug in the exact formulas from
-    // BiophysicalEnvelopeSpec / Tree-of-Life mapping tables).
+    // Mappers read only envelope, RoH and capability tier; no capability mutation.
+    // Formulas follow the Tree-of-Life mapping tables.
     let blood  = clamp01(map_blood(&input.envelope));
     let oxygen = clamp01(map_oxygen(&input.envelope));
     let wave   = clamp01(map_wave(&input.envelope));
 
     // RoH-based assets (DECAY, LIFEFORCE) as in the provable-spine spec.
-    let roh_norm = clamp01(input.roh.after / input.roh.ceiling); // 0..1 within ceiling
+    let roh_norm = input.roh.normalized_after(); // 0..1 within ceiling
     let decay     = roh_norm;           // higher RoH → higher DECAY
     let lifeforce = 1.0 - roh_norm;     // complement in [0,1]
 
     // Capability/evolution-derived axes (EVOLVE, BRAIN, SMART, NANO).
-    let evolve   = map_evolve(input.capability_state, input.evolve_index);
     let brain    = map_brain(input.capability_state);
-    let smart    = map_smart(input.capability_state);
+    let evolve   = map_evolve(input.evolve_index);
+    let smart    = clamp01(0.5 * brain + 0.5 * evolve);
     let nano     = map_nano(input.evolve_index);
 
     // Remaining fields follow the Tree-of-Life blueprint; all formulas documented
     // and sourced from existing envelope shards (no speculative signals).
     let h2o   = map_h2o_placeholder();     // currently neutral until hydration axis exists
-    let time  = map_time(&input.envelope);
+    let time  = map_time(input.epoch_index);
     let power = map_power(&input.envelope);
-    let tech  = map_tech(&input.envelope);
+    let tech  = map_tech(&input.envelope, brain);
     let fear  = map_fear(&input.envelope);
     let pain  = map_pain(&input.envelope);
 
@@ -92,3 +85,102 @@ pub fn neuroprint_from_snapshot(input: &NeuroPrintInput) -> NeuroPrintView {
         labels,
     }
 }
+
+fn clamp01(x: f32) -> f32 {
+    if x.is_nan() { 0.0 } else { x.clamp(0.0, 1.0) }
+}
+
+/// BLOOD: normalized heart rate.
+fn map_blood(env: &BiophysicalEnvelopeSnapshot) -> f32 {
+    env.hr_bpm_normalized.unwrap_or(0.5)
+}
+
+/// OXYGEN: HRV RMSSD as a reserve proxy.
+fn map_oxygen(env: &BiophysicalEnvelopeSnapshot) -> f32 {
+    env.hrv_rmssd_normalized.unwrap_or(0.5)
+}
+
+/// WAVE: mean of EEG alpha/beta/gamma bandpower and alpha-envelope CVE.
+fn map_wave(env: &BiophysicalEnvelopeSnapshot) -> f32 {
+    let bands = [
+        env.eeg_alpha_power_norm,
+        env.eeg_beta_power_norm,
+        env.eeg_gamma_power_norm,
+        env.eeg_alpha_cve_norm,
+    ];
+    bands.iter().map(|b| b.unwrap_or(0.5)).sum::<f32>() / bands.len() as f32
+}
+
+/// BRAIN: capability tier.
+fn map_brain(cap: CapabilityState) -> f32 {
+    match cap {
+        CapabilityState::CapModelOnly => 0.25,
+        CapabilityState::CapLabBench => 0.5,
+        CapabilityState::CapControlledHuman => 0.75,
+        CapabilityState::CapGeneralUse => 1.0,
+    }
+}
+
+/// EVOLVE: logged safe evolution steps over a 10 000-step window.
+fn map_evolve(evolve_index: Option<u64>) -> f32 {
+    evolve_index.map_or(0.0, |i| (i as f32 / 10_000.0).min(1.0))
+}
+
+/// NANO: discrete logged events over a 100 000-event window.
+fn map_nano(evolve_index: Option<u64>) -> f32 {
+    evolve_index.map_or(0.0, |i| (i as f32 / 100_000.0).min(1.0))
+}
+
+fn map_h2o_placeholder() -> f32 {
+    0.5
+}
+
+/// TIME: epoch index over a 10 000-epoch window.
+fn map_time(epoch_index: Option<u64>) -> f32 {
+    epoch_index.map_or(0.0, |e| (e as f32 / 10_000.0).min(1.0))
+}
+
+/// POWER: share of axes in WARN/RISK, RISK weighted higher.
+fn map_power(env: &BiophysicalEnvelopeSnapshot) -> f32 {
+    clamp01(0.4 * env.warn_axis_fraction.unwrap_or(0.0) + 0.6 * env.risk_axis_fraction.unwrap_or(0.0))
+}
+
+/// TECH: capability tier plus number of active axes (out of 32).
+fn map_tech(env: &BiophysicalEnvelopeSnapshot, brain: f32) -> f32 {
+    let axes = env.active_axis_count.map_or(0.0, |n| (n as f32 / 32.0).min(1.0));
+    clamp01(0.5 * brain + 0.5 * axes)
+}
+
+/// FEAR: sympathetic arousal from EDA and HR in WARN/RISK.
+fn map_fear(env: &BiophysicalEnvelopeSnapshot) -> f32 {
+    let fractions = [
+        env.eda_warn_fraction,
+        env.eda_risk_fraction,
+        env.hr_warn_fraction,
+        env.hr_risk_fraction,
+    ];
+    clamp01(fractions.iter().map(|f| clamp01(f.unwrap_or(0.0))).sum::<f32>() / 4.0)
+}
+
+/// PAIN: FEAR combined with motion instability.
+fn map_pain(env: &BiophysicalEnvelopeSnapshot) -> f32 {
+    let motion = (clamp01(env.motion_warn_fraction.unwrap_or(0.0))
+        + clamp01(env.motion_risk_fraction.unwrap_or(0.0)))
+        * 0.5;
+    clamp01(0.5 * map_fear(env) + 0.5 * motion)
+}
+
+/// Advisory HUD labels; never wired to capability transitions.
+fn derive_advisory_labels(decay: f32, lifeforce: f32, fear: f32, pain: f32) -> Vec<String> {
+    let mut labels = Vec::new();
+    if lifeforce > 0.7 && fear < 0.3 && pain < 0.3 {
+        labels.push("balanced".to_string());
+    }
+    if fear > 0.6 || pain > 0.6 {
+        labels.push("overloaded".to_string());
+    }
+    if decay > 0.7 {
+        labels.push("cooldown-recommended".to_string());
+    }
+    labels
+}
@@ -0,0 +1,60 @@
+use aln_core::{BiophysicalEnvelopeSnapshot, CapabilityState, RoHProjection, RoHScore};
+use neuroprint_core::{neuroprint, NeuroPrintInput};
+
+fn input(roh_after: f32) -> NeuroPrintInput {
+    NeuroPrintInput {
+        capability_state: CapabilityState::CapControlledHuman,
+        roh: RoHProjection::new(RoHScore::new(0.0), RoHScore::new(roh_after)),
+        envelope: BiophysicalEnvelopeSnapshot {
+            hr_bpm_normalized: Some(0.6),
+            eda_warn_fraction: Some(0.2),
+            active_axis_count: Some(16),
+            ..BiophysicalEnvelopeSnapshot::default()
+        },
+        evolve_index: Some(5_000),
+        epoch_index: None,
+    }
+}
+
+#[test]
+fn test_macro_projects_snapshot_into_unit_range() {
+    let view = neuroprint!(input(0.06));
+    assert_eq!(view.blood, 0.6);
+    assert_eq!(view.oxygen, 0.5);
+    assert_eq!(view.brain, 0.75);
+    assert_eq!(view.evolve, 0.5);
+    assert_eq!(view.smart, 0.625);
+    assert_eq!(view.tech, 0.625);
+    assert_eq!(view.time, 0.0);
+    assert!((view.decay - 0.2).abs() < 1e-6);
+    assert!((view.lifeforce - 0.8).abs() < 1e-6);
+    assert_eq!(view.labels, vec!["balanced".to_string()]);
+
+    for v in [
+        view.blood,
+        view.oxygen,
+        view.wave,
+        view.h2o,
+        view.time,
+        view.decay,
+        view.lifeforce,
+        view.brain,
+        view.smart,
+        view.evolve,
+        view.power,
+        view.tech,
+        view.fear,
+        view.pain,
+        view.nano,
+    ] {
+        assert!((0.0..=1.0).contains(&v));
+    }
+}
+
+#[test]
+fn test_roh_at_ceiling_recommends_cooldown() {
+    let view = neuroprint!(input(0.3));
+    assert_eq!(view.decay, 1.0);
+    assert_eq!(view.lifeforce, 0.0);
+    assert!(view.labels.contains(&"cooldown-recommended".to_string()));
+}
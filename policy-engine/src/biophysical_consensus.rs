@@ -1,34 +0,0 @@
-pub struct BiophysicalConsensusContext {
-    pub host_budget: HostBudget,
-    pub brain_specs: BrainSpecs,
-    pub default_evidence: EvidenceBundle,
-}
-
-pub struct BiophysicalDelta {
-    pub upgrade: UpgradeDescriptor,
-    pub telemetry_plan: TelemetryPlan,
-    pub host_snapshot: HostSnapshot,
-}
-
-pub enum DecisionReason {
-    Allowed,
-    DeniedRoHViolation,
-    DeniedEnvelopeViolation,
-    DeniedPaceViolation,
-    DeniedTelemetryViolation,
-    DeniedRodLifeforceHardStop,
-    DeniedEvidenceFailure,
-}
-
-pub fn validate_biophysical_block(
-    ctx: &BiophysicalConsensusContext,
-    proposal: &BiophysicalDelta,
-) -> DecisionReason {
-    // 1. Verify ALNComplianceParticle + 10-tag EvidenceBundle.
-    // 2. Recompute Ej, Mprot,j, Kbio,j, Sbio,j, duty, corridors.
-    // 3. Call QuantumphysicalReceding.step_is_safe / corridor_is_safe.
-    // 4. Call EnvelopePace.pacing_allows + MlDutyEnvelope guards.
-    // 5. Call TelemetricalOsteosis.plan_is_safe for telemetry quotas.
-    // 6. Compute ROD + LifeforceBand, enforce hard stops.
-    // 7. Return a single deterministic DecisionReason.
-}
@@ -1,7 +1,7 @@
 use std::fmt;
 
-use societal_sim::societal::model::SocietalState;
-use societal_sim::examples::phoenix_power_nano::{
+use microsociety_tree_of_life::societal::model::SocietalState;
+use microsociety_tree_of_life::examples::phoenix_power_nano::{
     phoenix_power_grid_nano_scenario_fast,
     phoenix_power_grid_nano_scenario_slow,
     run_steps,
@@ -8,9 +8,16 @@
 // - It does NOT talk to any blockchain or external IO; that is the job
 //   of a separate adapter layer that can hash and anchor these records.
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+use crate::doctrine::Doctrine;
 use crate::tokens::ExtendedTokenState;
+use crate::topology::Topology;
+use crate::world_invariants::{
+    GlobalInvariant, GlobalInvariantParams, GlobalViolation, WorldTotals,
+};
 
 /// Unique identifier types; you can alias or replace with your own.
 pub type SiteId = u64;
@@ -18,7 +25,7 @@ pub type AgentId = u64;
 pub type Tick = u64;
 
 /// Types of deeds that agents may propose unilaterally.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum DeedType {
     Help,
     Conflict,
@@ -37,6 +44,139 @@ pub enum DeedStatus {
     Blocked,
 }
 
+/// One invariant check that fired while processing a deed, with the threshold
+/// it was held to and the value that was observed.
+///
+/// Hard violations (ceilings, sovereignty, justice corridor, range, global
+/// limits) block, transform or downscale a deed; the rest record corrections
+/// the kernel applied or context it noticed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum InvariantOutcome {
+    BioloadCeiling { threshold: f64, observed: f64 },
+    DecayCeiling { threshold: f64, observed: f64 },
+    /// A spillover neighbor pushed over the per-site BIOLOAD ceiling.
+    SpilloverBioloadCeiling { site: SiteId, threshold: f64, observed: f64 },
+    /// A spillover neighbor pushed over the DECAY ceiling.
+    SpilloverDecayCeiling { site: SiteId, threshold: f64, observed: f64 },
+    /// `threshold` is k * CHURCH, `observed` the POWER before capping.
+    PowerCapped { threshold: f64, observed: f64 },
+    /// `threshold` is the FEAR band edge the value was clamped to.
+    FearClamped { threshold: f64, observed: f64 },
+    SovereigntyTooLow { threshold: f64, observed: f64 },
+    JusticeCorridor { threshold: f64, observed: f64 },
+    /// Low JUSTICE noted for a neutral or positive deed; not blocking.
+    JusticeBelowPreferred { threshold: f64, observed: f64 },
+    /// `threshold` is the topology radius, `observed` the target's distance.
+    TargetOutOfRange { threshold: f64, observed: f64 },
+    GlobalPowerBudget { threshold: f64, observed: f64 },
+    TotalBioload { threshold: f64, observed: f64 },
+    AggregateFear { threshold: f64, observed: f64 },
+}
+
+impl InvariantOutcome {
+    pub fn threshold(&self) -> f64 {
+        self.values().0
+    }
+
+    pub fn observed(&self) -> f64 {
+        self.values().1
+    }
+
+    /// True for outcomes that make a deed unacceptable as requested.
+    pub fn is_violation(&self) -> bool {
+        !matches!(
+            self,
+            InvariantOutcome::PowerCapped { .. }
+                | InvariantOutcome::FearClamped { .. }
+                | InvariantOutcome::JusticeBelowPreferred { .. }
+        )
+    }
+
+    fn values(&self) -> (f64, f64) {
+        use InvariantOutcome::*;
+        match *self {
+            BioloadCeiling { threshold, observed }
+            | DecayCeiling { threshold, observed }
+            | SpilloverBioloadCeiling { threshold, observed, .. }
+            | SpilloverDecayCeiling { threshold, observed, .. }
+            | PowerCapped { threshold, observed }
+            | FearClamped { threshold, observed }
+            | SovereigntyTooLow { threshold, observed }
+            | JusticeCorridor { threshold, observed }
+            | JusticeBelowPreferred { threshold, observed }
+            | TargetOutOfRange { threshold, observed }
+            | GlobalPowerBudget { threshold, observed }
+            | TotalBioload { threshold, observed }
+            | AggregateFear { threshold, observed } => (threshold, observed),
+        }
+    }
+}
+
+impl From<GlobalViolation> for InvariantOutcome {
+    fn from(v: GlobalViolation) -> Self {
+        use InvariantOutcome::*;
+        let (threshold, observed) = (v.limit, v.observed);
+        match v.invariant {
+            GlobalInvariant::PowerBudget => GlobalPowerBudget { threshold, observed },
+            GlobalInvariant::TotalBioload => TotalBioload { threshold, observed },
+            GlobalInvariant::AggregateFear => AggregateFear { threshold, observed },
+        }
+    }
+}
+
+impl fmt::Display for InvariantOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (t, o) = self.values();
+        match self {
+            InvariantOutcome::BioloadCeiling { .. } => {
+                write!(f, "BIOLOAD {:.3} over ceiling {:.3}", o, t)
+            }
+            InvariantOutcome::DecayCeiling { .. } => {
+                write!(f, "DECAY {:.3} over ceiling {:.3}", o, t)
+            }
+            InvariantOutcome::SpilloverBioloadCeiling { site, .. } => {
+                write!(f, "spillover BIOLOAD {:.3} at site {} over ceiling {:.3}", o, site, t)
+            }
+            InvariantOutcome::SpilloverDecayCeiling { site, .. } => {
+                write!(f, "spillover DECAY {:.3} at site {} over ceiling {:.3}", o, site, t)
+            }
+            InvariantOutcome::PowerCapped { .. } => {
+                write!(f, "POWER {:.3} capped by k * CHURCH at {:.3}", o, t)
+            }
+            InvariantOutcome::FearClamped { .. } => {
+                write!(f, "FEAR {:.3} clamped into safe band at {:.3}", o, t)
+            }
+            InvariantOutcome::SovereigntyTooLow { .. } => write!(
+                f,
+                "inadequate SOVEREIGNTY {:.3} for invasive/high-impact deed (min {:.3})",
+                o, t
+            ),
+            InvariantOutcome::JusticeCorridor { .. } => write!(
+                f,
+                "justice corridor tightened (UNFAIRDRAIN risk): JUSTICE {:.3} below {:.3}",
+                o, t
+            ),
+            InvariantOutcome::JusticeBelowPreferred { .. } => {
+                write!(f, "justice below preferred corridor ({:.3} < {:.3})", o, t)
+            }
+            InvariantOutcome::TargetOutOfRange { .. } => write!(
+                f,
+                "target site out of range for topology (distance {} > radius {})",
+                o, t
+            ),
+            InvariantOutcome::GlobalPowerBudget { .. } => {
+                write!(f, "global POWER budget exceeded ({:.3} > {:.3})", o, t)
+            }
+            InvariantOutcome::TotalBioload { .. } => {
+                write!(f, "total BIOLOAD ceiling exceeded ({:.3} > {:.3})", o, t)
+            }
+            InvariantOutcome::AggregateFear { .. } => {
+                write!(f, "aggregate FEAR ceiling exceeded ({:.3} > {:.3})", o, t)
+            }
+        }
+    }
+}
+
 /// Minimal, deterministic record of a processed deed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidatedDeed {
@@ -49,14 +189,70 @@ pub struct ValidatedDeed {
     pub source_site: SiteId,
     pub target_site: Option<SiteId>,
 
-    // Human-readable or machine-parsable reason(s) for block/transform.
+    // Human-readable reason for block/transform; a rendering of `outcomes`.
     pub reason: String,
 
+    // Every invariant that fired, in the order the kernel checked them.
+    #[serde(default)]
+    pub outcomes: Vec<InvariantOutcome>,
+
+    // Intensity asked for (clamped to [0, 1]) and the intensity actually
+    // applied; they differ when the kernel downscaled the deed, and the
+    // applied intensity is 0 for blocked deeds.
+    #[serde(default)]
+    pub requested_intensity: f64,
+    #[serde(default)]
+    pub intensity: f64,
+
     // Snapshots of pre-/post-state for auditing.
     pub pre_source: ExtendedTokenState,
     pub post_source: ExtendedTokenState,
     pub pre_target: Option<ExtendedTokenState>,
     pub post_target: Option<ExtendedTokenState>,
+
+    // Neighboring sites (other than the target) touched by spillover.
+    #[serde(default)]
+    pub spillover: Vec<SpilloverEffect>,
+
+    // Content hash of the doctrine the kernel was configured from, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doctrine_hash: Option<String>,
+}
+
+impl ValidatedDeed {
+    /// Human-readable summary of `status` and `outcomes`, as stored in `reason`.
+    pub fn render_reason(&self) -> String {
+        let lead = match self.status {
+            DeedStatus::Blocked => "Blocked".to_string(),
+            DeedStatus::Transformed if self.deed_type != self.original_type => {
+                format!("Transformed to {:?}", self.deed_type)
+            }
+            DeedStatus::Transformed if self.intensity < self.requested_intensity => format!(
+                "Downscaled intensity {:.4} -> {:.4}",
+                self.requested_intensity, self.intensity
+            ),
+            _ => "OK".to_string(),
+        };
+        if self.outcomes.is_empty() {
+            return lead;
+        }
+        let details = self
+            .outcomes
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ");
+        format!("{}: {}", lead, details)
+    }
+}
+
+/// Pre-/post-state of a neighbor hit by a deed's spillover.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpilloverEffect {
+    pub site: SiteId,
+    pub distance: u64,
+    pub pre: ExtendedTokenState,
+    pub post: ExtendedTokenState,
 }
 
 /// Unilateral deed request from an agent.
@@ -76,8 +272,9 @@ pub struct DeedRequest {
 }
 
 /// Global scalar parameters for invariants and tuning.
-/// You can load these from configuration or a doctrine file.
+/// Load these from a doctrine file with `doctrine::Doctrine::load`.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct InvariantParams {
     pub roh_max: f64,            // e.g., 0.3
     pub decay_max: f64,          // always 1.0 in your doctrine
@@ -109,17 +306,54 @@ impl WorldLine {
     pub fn len(&self) -> usize {
         self.sites.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.sites.is_empty()
+    }
 }
 
 /// Centralized validation kernel implementing Neuromorph-GOD invariants
 /// and deed transformations.
 pub struct ValidationKernel {
     pub invariants: InvariantParams,
+    /// Site adjacency; `None` lets any site target any other and confines
+    /// spillover to the target.
+    pub topology: Option<Topology>,
+    /// World-level limits checked after every deed; `None` disables them.
+    pub global: Option<GlobalInvariantParams>,
+    /// Stamped onto every ValidatedDeed this kernel produces.
+    pub doctrine_hash: Option<String>,
 }
 
 impl ValidationKernel {
     pub fn new(invariants: InvariantParams) -> Self {
-        Self { invariants }
+        Self {
+            invariants,
+            topology: None,
+            global: None,
+            doctrine_hash: None,
+        }
+    }
+
+    /// Kernel that rejects out-of-range targets and spreads spillover over `topology`.
+    pub fn with_topology(invariants: InvariantParams, topology: Topology) -> Self {
+        Self {
+            invariants,
+            topology: Some(topology),
+            global: None,
+            doctrine_hash: None,
+        }
+    }
+
+    /// Kernel configured from a validated doctrine: its invariants, its
+    /// global limits (if any) and its content hash.
+    pub fn from_doctrine(doctrine: &Doctrine) -> Self {
+        Self {
+            invariants: doctrine.invariants,
+            topology: None,
+            global: doctrine.global,
+            doctrine_hash: Some(doctrine.content_hash().to_string()),
+        }
     }
 
     /// Main entry point: validate and apply a deed request.
@@ -127,32 +361,161 @@ impl ValidationKernel {
     /// - Reads pre-states from `world`,
     /// - Computes hypothetical post-states,
     /// - Enforces invariants and possibly transforms the deed,
+    /// - Downscales the intensity instead when a smaller deed would be safe,
     /// - Applies the final, permitted change to `world`,
     /// - Returns a ValidatedDeed for audit / blockchain anchoring.
     pub fn process_deed(
         &self,
         world: &mut WorldLine,
         req: DeedRequest,
+    ) -> Option<ValidatedDeed> {
+        let mut deed = self.apply_deed(world, req)?;
+        deed.doctrine_hash = self.doctrine_hash.clone();
+        Some(deed)
+    }
+
+    fn apply_deed(
+        &self,
+        world: &mut WorldLine,
+        req: DeedRequest,
     ) -> Option<ValidatedDeed> {
         // Fetch source state
-        let src = world.get(req.source_site)?.clone();
-        let mut src_new = src;
+        let src = *world.get(req.source_site)?;
 
         // Fetch optional target
-        let (tgt_opt, mut tgt_new_opt) = if let Some(tid) = req.target_site {
-            let t = world.get(tid)?.clone();
-            (Some(t), Some(t))
-        } else {
-            (None, None)
+        let tgt_opt = match req.target_site {
+            Some(tid) => Some(*world.get(tid)?),
+            None => None,
         };
 
         // Ensure intensity non-negative and bounded for stability.
-        let intensity = if req.intensity < 0.0 {
-            0.0
-        } else if req.intensity > 1.0 {
-            1.0
-        } else {
-            req.intensity
+        let requested = req.intensity.clamp(0.0, 1.0);
+
+        // Help, Conflict and Colonize only reach sites within the topology's radius.
+        if let (Some(topology), Some(tid)) = (self.topology, req.target_site) {
+            let reach_limited = matches!(
+                req.deed_type,
+                DeedType::Help | DeedType::Conflict | DeedType::Colonize
+            );
+            if reach_limited && !topology.in_range(req.source_site, tid, world.len()) {
+                let distance = topology.distance(req.source_site, tid, world.len()).unwrap_or(0);
+                let outcome = InvariantOutcome::TargetOutOfRange {
+                    threshold: topology.radius() as f64,
+                    observed: distance as f64,
+                };
+                return Some(blocked_deed(
+                    &req,
+                    req.deed_type,
+                    vec![outcome],
+                    src,
+                    tgt_opt,
+                    requested,
+                ));
+            }
+        }
+
+        let totals_before = self.global.map(|_| WorldTotals::of(world));
+        let ctx = TrialContext {
+            world,
+            req: &req,
+            src: &src,
+            tgt_pre: tgt_opt.as_ref(),
+            totals_before: totals_before.as_ref(),
+        };
+
+        // Try the deed as requested; if it breaks a hard invariant, look for
+        // the largest intensity that does not.
+        // A downscaled deed lists what the full deed would have violated first.
+        let mut trial = self.evaluate(&ctx, requested);
+        let mut intensity = requested;
+        let mut outcomes = Vec::new();
+        if !trial.acceptable(req.deed_type) {
+            if let Some(safe) = self.largest_safe_intensity(&ctx, requested) {
+                outcomes = trial.violations();
+                trial = self.evaluate(&ctx, safe);
+                trial.status = DeedStatus::Transformed;
+                intensity = safe;
+            }
+        }
+
+        // If blocked, do not apply any changes to the world.
+        if trial.status == DeedStatus::Blocked {
+            outcomes.extend(trial.outcomes);
+            return Some(blocked_deed(
+                &req,
+                trial.final_type,
+                outcomes,
+                src,
+                tgt_opt,
+                requested,
+            ));
+        }
+        if !trial.spillover_breaches.is_empty() || !trial.global_breaches.is_empty() {
+            outcomes.extend(trial.violations());
+            return Some(blocked_deed(
+                &req,
+                req.deed_type,
+                outcomes,
+                src,
+                tgt_opt,
+                requested,
+            ));
+        }
+        outcomes.extend(trial.outcomes);
+
+        // Apply final states to world (success or transformed).
+        if let Some(src_site) = world.get_mut(req.source_site) {
+            *src_site = trial.src_final;
+        }
+        if let (Some(tid), Some(tgt_final)) = (req.target_site, trial.tgt_final) {
+            if let Some(tgt_site) = world.get_mut(tid) {
+                *tgt_site = tgt_final;
+            }
+        }
+        for effect in &trial.spillover {
+            if let Some(site) = world.get_mut(effect.site) {
+                *site = effect.post;
+            }
+        }
+
+        let mut deed = ValidatedDeed {
+            tick: req.tick,
+            deed_id: req.deed_id,
+            proposer: req.proposer,
+            deed_type: trial.final_type,
+            original_type: req.deed_type,
+            status: trial.status,
+            source_site: req.source_site,
+            target_site: req.target_site,
+            reason: String::new(),
+            outcomes,
+            requested_intensity: requested,
+            intensity,
+            pre_source: src,
+            post_source: trial.src_final,
+            pre_target: tgt_opt,
+            post_target: trial.tgt_final,
+            spillover: trial.spillover,
+            doctrine_hash: None,
+        };
+        deed.reason = deed.render_reason();
+        Some(deed)
+    }
+
+    /// Run the deed rules and every invariant at `intensity` without touching the world.
+    fn evaluate(&self, ctx: &TrialContext<'_>, intensity: f64) -> Trial {
+        let req = ctx.req;
+        let world = ctx.world;
+        let mut src_new = *ctx.src;
+        let mut tgt_new_opt = ctx.tgt_pre.copied();
+
+        // Pollution spillover falls off with distance; unreachable targets get none.
+        let target_spill = match (self.topology, req.target_site) {
+            (None, _) => 0.5,
+            (Some(topology), Some(tid)) if topology.in_range(req.source_site, tid, world.len()) => {
+                0.5 / topology.distance(req.source_site, tid, world.len()).unwrap_or(1) as f64
+            }
+            (Some(_), _) => 0.0,
         };
 
         // Apply pedagogical update rule to hypothetical copies.
@@ -164,7 +527,12 @@ impl ValidationKernel {
                 self.apply_repair_rule(&mut src_new, intensity);
             }
             DeedType::EmitPollution => {
-                self.apply_emit_pollution_rule(&mut src_new, tgt_new_opt.as_mut(), intensity);
+                self.apply_emit_pollution_rule(
+                    &mut src_new,
+                    tgt_new_opt.as_mut(),
+                    intensity,
+                    target_spill,
+                );
             }
             DeedType::Conflict => {
                 self.apply_conflict_rule(&mut src_new, tgt_new_opt.as_mut(), intensity);
@@ -184,60 +552,130 @@ impl ValidationKernel {
         }
 
         // Check invariants and possibly transform / scale.
-        let (status, final_type, reason, src_final, tgt_final_opt) =
+        let (status, final_type, outcomes, src_final, tgt_final) =
             self.enforce_invariants(
                 req.deed_type,
-                &src,
+                ctx.src,
                 &src_new,
-                tgt_opt.as_ref(),
+                ctx.tgt_pre,
                 tgt_new_opt.as_ref(),
                 intensity,
             );
 
-        // If blocked, do not apply any changes to the world.
-        if status == DeedStatus::Blocked {
-            return Some(ValidatedDeed {
-                tick: req.tick,
-                deed_id: req.deed_id,
-                proposer: req.proposer,
-                deed_type: final_type,
-                original_type: req.deed_type,
-                status,
-                source_site: req.source_site,
-                target_site: req.target_site,
-                reason,
-                pre_source: src,
-                post_source: src,
-                pre_target: tgt_opt,
-                post_target: tgt_opt,
-            });
+        let spillover = if status != DeedStatus::Blocked && final_type == DeedType::EmitPollution {
+            self.pollution_spillover(world, req, intensity)
+        } else {
+            Vec::new()
+        };
+        let spillover_breaches = self.spillover_breaches(&spillover);
+
+        // Global invariants over the world as it would be after the deed.
+        let global_breaches = match (self.global, ctx.totals_before) {
+            (Some(global), Some(before)) if status != DeedStatus::Blocked => {
+                let n = world.len();
+                let mut after = *before;
+                after.apply_change(ctx.src, &src_final, n);
+                if let (Some(pre), Some(post)) = (ctx.tgt_pre, tgt_final.as_ref()) {
+                    after.apply_change(pre, post, n);
+                }
+                for effect in &spillover {
+                    after.apply_change(&effect.pre, &effect.post, n);
+                }
+                global.breaches(before, &after)
+            }
+            _ => Vec::new(),
+        };
+
+        Trial {
+            status,
+            final_type,
+            outcomes,
+            src_final,
+            tgt_final,
+            spillover,
+            spillover_breaches,
+            global_breaches,
         }
+    }
 
-        // Apply final states to world (success or transformed).
-        if let Some(src_site) = world.get_mut(req.source_site) {
-            *src_site = src_final;
+    /// Largest intensity in (0, `requested`] that passes every hard invariant.
+    ///
+    /// The deed rules are linear in intensity, so safety is monotone and
+    /// bisection converges on the boundary. Returns `None` when not even a
+    /// vanishing deed would be safe.
+    fn largest_safe_intensity(&self, ctx: &TrialContext<'_>, requested: f64) -> Option<f64> {
+        if !self.evaluate(ctx, 0.0).acceptable(ctx.req.deed_type) {
+            return None;
         }
-        if let (Some(tid), Some(tgt_final)) = (req.target_site, tgt_final_opt) {
-            if let Some(tgt_site) = world.get_mut(tid) {
-                *tgt_site = tgt_final;
+        let (mut lo, mut hi) = (0.0, requested);
+        for _ in 0..BISECTION_STEPS {
+            let mid = 0.5 * (lo + hi);
+            if self.evaluate(ctx, mid).acceptable(ctx.req.deed_type) {
+                lo = mid;
+            } else {
+                hi = mid;
             }
         }
+        (lo > 0.0).then_some(lo)
+    }
 
-        Some(ValidatedDeed {
-            tick: req.tick,
-            deed_id: req.deed_id,
-            proposer: req.proposer,
-            deed_type: final_type,
-            original_type: req.deed_type,
-            status,
-            source_site: req.source_site,
-            target_site: req.target_site,
-            reason,
-            pre_source: src,
-            post_source: src_final,
-            pre_target: tgt_opt,
-            post_target: tgt_final_opt,
-        })
+    /// Spillover of an emission onto every topology neighbor other than the
+    /// target, at `0.5 / distance` of the source amount.
+    fn pollution_spillover(
+        &self,
+        world: &WorldLine,
+        req: &DeedRequest,
+        intensity: f64,
+    ) -> Vec<SpilloverEffect> {
+        let Some(topology) = self.topology else {
+            return Vec::new();
+        };
+        let mut effects = Vec::new();
+        for (site, distance) in topology.neighbors(req.source_site, world.len()) {
+            if Some(site) == req.target_site {
+                continue;
+            }
+            let Some(pre) = world.get(site).copied() else {
+                continue;
+            };
+            let mut post = pre;
+            let spill = 0.5 / distance as f64;
+            post.add_pollution(spill * 0.02 * intensity, spill * 0.01 * intensity);
+            post.fear += spill * 0.02 * intensity;
+            post.clamp_invariants();
+            effects.push(SpilloverEffect {
+                site,
+                distance,
+                pre,
+                post,
+            });
+        }
+        effects
+    }
+
+    /// Per-site BIOLOAD / DECAY ceilings for spillover neighbors. Only
+    /// sites the spillover pushes further past a ceiling count, so a
+    /// neighbor already over it does not block deeds that leave it alone.
+    fn spillover_breaches(&self, spillover: &[SpilloverEffect]) -> Vec<InvariantOutcome> {
+        let mut breaches = Vec::new();
+        for effect in spillover {
+            let (pre, post) = (&effect.pre, &effect.post);
+            if post.decay > self.invariants.decay_max && post.decay > pre.decay {
+                breaches.push(InvariantOutcome::SpilloverDecayCeiling {
+                    site: effect.site,
+                    threshold: self.invariants.decay_max,
+                    observed: post.decay,
+                });
+            }
+            if post.bioload > self.invariants.bioload_max_site && post.bioload > pre.bioload {
+                breaches.push(InvariantOutcome::SpilloverBioloadCeiling {
+                    site: effect.site,
+                    threshold: self.invariants.bioload_max_site,
+                    observed: post.bioload,
+                });
+            }
+        }
+        breaches
     }
 
     // ---------- Pedagogical deed rules on hypothetical states ----------
@@ -303,6 +741,7 @@ impl ValidationKernel {
         src: &mut ExtendedTokenState,
         tgt_opt: Option<&mut ExtendedTokenState>,
         intensity: f64,
+        target_spill: f64,
     ) {
         let pollution_amount = 0.02 * intensity;
         let bioload_gain = 0.01 * intensity;
@@ -313,11 +752,11 @@ impl ValidationKernel {
         }
         src.add_pollution(pollution_amount, bioload_gain);
 
-        // Spillover to target if present.
+        // Spillover to target if present, scaled by how far away it is.
         if let Some(tgt) = tgt_opt {
-            tgt.add_pollution(0.5 * pollution_amount, 0.5 * bioload_gain);
+            tgt.add_pollution(target_spill * pollution_amount, target_spill * bioload_gain);
             // FEAR rises from exposure to pollution.
-            tgt.fear += 0.01 * intensity;
+            tgt.fear += 2.0 * target_spill * 0.01 * intensity;
         }
 
         // Source FEAR increase as well.
@@ -327,7 +766,7 @@ impl ValidationKernel {
     fn apply_conflict_rule(
         &self,
         src: &mut ExtendedTokenState,
-        tgt_opt: Option<&mut ExtendedTokenState>,
+        mut tgt_opt: Option<&mut ExtendedTokenState>,
         intensity: f64,
     ) {
         let church_loss = 0.02 * intensity;
@@ -337,7 +776,7 @@ impl ValidationKernel {
         let decay_increase = 0.01 * intensity;
         let justice_loss = 0.02 * intensity;
 
-        if let Some(tgt) = tgt_opt {
+        if let Some(tgt) = tgt_opt.as_deref_mut() {
             src.apply_conflict_with(
                 tgt,
                 church_loss,
@@ -373,13 +812,13 @@ impl ValidationKernel {
         if let Some(tgt) = tgt_opt {
             src.apply_help_deed_with(
                 tgt,
-                power_cost = 0.01 * intensity,
-                church_gain_other = 0.02 * intensity,
-                lifeforce_gain_other = 0.02 * intensity,
-                trust_gain = 0.02 * intensity,
-                bioload_gain_self = 0.005 * intensity,
-                bioload_gain_other = 0.002 * intensity,
-                sacrifice_gain_self = 0.01 * intensity,
+                0.01 * intensity,  // power_cost
+                0.02 * intensity,  // church_gain_other
+                0.02 * intensity,  // lifeforce_gain_other
+                0.02 * intensity,  // trust_gain
+                0.005 * intensity, // bioload_gain_self
+                0.002 * intensity, // bioload_gain_other
+                0.01 * intensity,  // sacrifice_gain_self
             );
         } else {
             // Self-support: small self-repair / trust.
@@ -418,29 +857,37 @@ impl ValidationKernel {
     ) -> (
         DeedStatus,
         DeedType,
-        String,
+        Vec<InvariantOutcome>,
         ExtendedTokenState,
         Option<ExtendedTokenState>,
     ) {
         // Start by assuming we accept the hypothetical new states.
         let mut status = DeedStatus::Success;
         let mut final_type = deed_type;
-        let mut reason = String::from("OK");
+        let mut outcomes = Vec::new();
 
         let mut src_final = *src_new;
         let mut tgt_final = tgt_new.cloned();
 
         // 1. Biophysical safety: DECAY, LIFEFORCE band, BIOLOAD ceiling.
-        if src_new.decay > self.invariants.decay_max
-            || src_new.bioload > self.invariants.bioload_max_site
-        {
+        if src_new.decay > self.invariants.decay_max {
+            outcomes.push(InvariantOutcome::DecayCeiling {
+                threshold: self.invariants.decay_max,
+                observed: src_new.decay,
+            });
+        }
+        if src_new.bioload > self.invariants.bioload_max_site {
+            outcomes.push(InvariantOutcome::BioloadCeiling {
+                threshold: self.invariants.bioload_max_site,
+                observed: src_new.bioload,
+            });
+        }
+        if !outcomes.is_empty() {
             // Try to transform harmful deeds into Repair when meaningful.
             match deed_type {
                 DeedType::Conflict | DeedType::EmitPollution | DeedType::DeployTech => {
                     status = DeedStatus::Transformed;
                     final_type = DeedType::Repair;
-                    reason = "Transformed to Repair: proposed state would violate DECAY or BIOLOAD ceiling"
-                        .to_string();
 
                     // Fall back to a conservative repair-style correction on pre-state.
                     src_final = *src_pre;
@@ -455,9 +902,7 @@ impl ValidationKernel {
                 _ => {
                     status = DeedStatus::Blocked;
                     final_type = deed_type;
-                    reason = "Blocked: proposed state would violate DECAY or BIOLOAD ceiling"
-                        .to_string();
-                    return (status, final_type, reason, *src_pre, tgt_pre.cloned());
+                    return (status, final_type, outcomes, *src_pre, tgt_pre.cloned());
                 }
             }
         }
@@ -469,10 +914,10 @@ impl ValidationKernel {
 
         if tmp_src.power < src_final.power {
             status = DeedStatus::Transformed;
-            reason = format!(
-                "{}; POWER capped by k * CHURCH",
-                reason
-            );
+            outcomes.push(InvariantOutcome::PowerCapped {
+                threshold: tmp_src.power,
+                observed: src_final.power,
+            });
             src_final = tmp_src;
         }
 
@@ -481,13 +926,17 @@ impl ValidationKernel {
             || src_final.fear > self.invariants.fear_max
         {
             // Clamp but do not block: FEAR is a signal; biophysical corridors are stricter.
-            src_final.fear = clamp(
+            let clamped = clamp(
                 src_final.fear,
                 self.invariants.fear_min,
                 self.invariants.fear_max,
             );
+            outcomes.push(InvariantOutcome::FearClamped {
+                threshold: clamped,
+                observed: src_final.fear,
+            });
+            src_final.fear = clamped;
             status = DeedStatus::Transformed;
-            reason = format!("{}; FEAR clamped into safe band", reason);
         }
 
         // Invasive deeds require minimum sovereignty.
@@ -498,30 +947,112 @@ impl ValidationKernel {
         if invasive && src_pre.sovereignty < self.invariants.sovereignty_min_invasive {
             status = DeedStatus::Blocked;
             final_type = deed_type;
-            reason = "Blocked: inadequate SOVEREIGNTY for invasive/high-impact deed".to_string();
-            return (status, final_type, reason, *src_pre, tgt_pre.cloned());
+            let outcome = InvariantOutcome::SovereigntyTooLow {
+                threshold: self.invariants.sovereignty_min_invasive,
+                observed: src_pre.sovereignty,
+            };
+            return (status, final_type, vec![outcome], *src_pre, tgt_pre.cloned());
         }
 
         // 3. Justice corridors: prevent worsening UNFAIRDRAIN beyond threshold.
         if src_new.justice < self.invariants.justice_min {
+            let (threshold, observed) = (self.invariants.justice_min, src_new.justice);
             match deed_type {
                 DeedType::EmitPollution | DeedType::Conflict => {
                     status = DeedStatus::Blocked;
                     final_type = deed_type;
-                    reason = "Blocked: justice corridor tightened (UNFAIRDRAIN risk)".to_string();
-                    return (status, final_type, reason, *src_pre, tgt_pre.cloned());
+                    let outcome = InvariantOutcome::JusticeCorridor { threshold, observed };
+                    return (status, final_type, vec![outcome], *src_pre, tgt_pre.cloned());
                 }
                 _ => {
                     // For neutral/positive deeds we allow but log the low-justice context.
-                    reason = format!("{}; justice below preferred corridor", reason);
+                    outcomes.push(InvariantOutcome::JusticeBelowPreferred { threshold, observed });
                 }
             }
         }
 
-        (status, final_type, reason, src_final, tgt_final)
+        (status, final_type, outcomes, src_final, tgt_final)
+    }
+}
+
+/// Bisection steps in the intensity search; 2^-40 is far below any token's precision.
+const BISECTION_STEPS: u32 = 40;
+
+/// Everything a trial evaluation reads; fixed for one request.
+struct TrialContext<'a> {
+    world: &'a WorldLine,
+    req: &'a DeedRequest,
+    src: &'a ExtendedTokenState,
+    tgt_pre: Option<&'a ExtendedTokenState>,
+    totals_before: Option<&'a WorldTotals>,
+}
+
+/// Outcome of evaluating a deed at one intensity.
+struct Trial {
+    status: DeedStatus,
+    final_type: DeedType,
+    outcomes: Vec<InvariantOutcome>,
+    src_final: ExtendedTokenState,
+    tgt_final: Option<ExtendedTokenState>,
+    spillover: Vec<SpilloverEffect>,
+    spillover_breaches: Vec<InvariantOutcome>,
+    global_breaches: Vec<GlobalViolation>,
+}
+
+impl Trial {
+    /// Passed every hard invariant as the deed that was asked for. POWER caps
+    /// and FEAR clamps are corrections, not violations.
+    fn acceptable(&self, original: DeedType) -> bool {
+        self.status != DeedStatus::Blocked
+            && self.final_type == original
+            && self.spillover_breaches.is_empty()
+            && self.global_breaches.is_empty()
+    }
+
+    /// The hard violations that made this trial unacceptable.
+    fn violations(&self) -> Vec<InvariantOutcome> {
+        self.outcomes
+            .iter()
+            .copied()
+            .filter(InvariantOutcome::is_violation)
+            .chain(self.spillover_breaches.iter().copied())
+            .chain(self.global_breaches.iter().copied().map(Into::into))
+            .collect()
     }
 }
 
+fn blocked_deed(
+    req: &DeedRequest,
+    deed_type: DeedType,
+    outcomes: Vec<InvariantOutcome>,
+    src: ExtendedTokenState,
+    tgt_opt: Option<ExtendedTokenState>,
+    requested_intensity: f64,
+) -> ValidatedDeed {
+    let mut deed = ValidatedDeed {
+        tick: req.tick,
+        deed_id: req.deed_id,
+        proposer: req.proposer,
+        deed_type,
+        original_type: req.deed_type,
+        status: DeedStatus::Blocked,
+        source_site: req.source_site,
+        target_site: req.target_site,
+        reason: String::new(),
+        outcomes,
+        requested_intensity,
+        intensity: 0.0,
+        pre_source: src,
+        post_source: src,
+        pre_target: tgt_opt,
+        post_target: tgt_opt,
+        spillover: Vec::new(),
+        doctrine_hash: None,
+    };
+    deed.reason = deed.render_reason();
+    deed
+}
+
 // Local helper re-exported because we used it above.
 fn clamp(v: f64, min: f64, max: f64) -> f64 {
     if v < min {
@@ -0,0 +1,195 @@
+// src/doctrine.rs
+// Doctrine files: one TOML or JSON document that populates every kernel
+// parameter struct, validated before anything runs.
+//
+// A doctrine file has an [invariants] and a [spectral] table, plus optional
+// [vocab], [global] and [transport] tables; unknown tables and unknown keys
+// inside any table are rejected, so a misspelled limit cannot silently fall
+// back to nothing. The SHA-256 of the file's bytes is the doctrine's content hash,
+// which kernels built from it stamp onto every record they produce.
+
+use std::cmp::Ordering;
+use std::fs;
+use std::path::Path;
+
+use microsociety_vocab::VocabBands;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::deeds::InvariantParams;
+use crate::spectral::SpectralParams;
+use crate::transport::TransportParams;
+use crate::utils::crypto::compute_sha256_hash;
+use crate::world_invariants::GlobalInvariantParams;
+
+/// Hard upper bound on `roh_max` that no doctrine may relax.
+pub const ROH_MAX_CEILING: f64 = 0.3;
+
+/// Reasons a doctrine file is refused.
+#[derive(Debug, Error)]
+pub enum DoctrineError {
+    #[error("cannot read doctrine file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("doctrine file {path} has no .toml or .json extension")]
+    UnknownFormat { path: String },
+
+    #[error("malformed TOML doctrine: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("malformed JSON doctrine: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("roh_max {roh_max} exceeds the doctrine ceiling of {ROH_MAX_CEILING}")]
+    RohMaxTooHigh { roh_max: f64 },
+
+    #[error("{field}: lower bound {min} is not below upper bound {max}")]
+    InvertedBand {
+        field: &'static str,
+        min: f64,
+        max: f64,
+    },
+
+    #[error("{field}: ceiling {value} is negative or not finite")]
+    NegativeCeiling { field: &'static str, value: f64 },
+
+    #[error("{field}: rate {value} is outside [0, 1]")]
+    RateOutOfRange { field: &'static str, value: f64 },
+
+    #[error("{field}: weight {value} is negative or not finite")]
+    NegativeWeight { field: &'static str, value: f64 },
+}
+
+/// Every kernel parameter struct, as loaded from one doctrine file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Doctrine {
+    pub invariants: InvariantParams,
+    pub spectral: SpectralParams,
+    #[serde(default)]
+    pub vocab: VocabBands,
+    #[serde(default)]
+    pub global: Option<GlobalInvariantParams>,
+    #[serde(default)]
+    pub transport: Option<TransportParams>,
+
+    #[serde(skip)]
+    content_hash: String,
+}
+
+impl Doctrine {
+    /// Load and validate a `.toml` or `.json` doctrine file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, DoctrineError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Self::from_toml_str(&text),
+            Some("json") => Self::from_json_str(&text),
+            _ => Err(DoctrineError::UnknownFormat {
+                path: path.display().to_string(),
+            }),
+        }
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, DoctrineError> {
+        let doctrine: Self = toml::from_str(text)?;
+        doctrine.sealed(text)
+    }
+
+    pub fn from_json_str(text: &str) -> Result<Self, DoctrineError> {
+        let doctrine: Self = serde_json::from_str(text)?;
+        doctrine.sealed(text)
+    }
+
+    /// Hex SHA-256 of the source document's bytes.
+    pub fn content_hash(&self) -> &str {
+        &self.content_hash
+    }
+
+    /// Check the schema rules every doctrine must satisfy.
+    pub fn validate(&self) -> Result<(), DoctrineError> {
+        let inv = &self.invariants;
+        if inv.roh_max > ROH_MAX_CEILING || inv.roh_max.is_nan() {
+            return Err(DoctrineError::RohMaxTooHigh {
+                roh_max: inv.roh_max,
+            });
+        }
+
+        let bands = [
+            ("invariants.fear_min/fear_max", inv.fear_min, inv.fear_max),
+            (
+                "spectral.fear_low_band/fear_high_band",
+                self.spectral.fear_low_band,
+                self.spectral.fear_high_band,
+            ),
+            (
+                "vocab.fear_min_safe/fear_max_safe",
+                self.vocab.fear_min_safe,
+                self.vocab.fear_max_safe,
+            ),
+        ];
+        for (field, min, max) in bands {
+            if min.partial_cmp(&max) != Some(Ordering::Less) {
+                return Err(DoctrineError::InvertedBand { field, min, max });
+            }
+        }
+
+        let mut ceilings = vec![
+            ("invariants.roh_max", inv.roh_max),
+            ("invariants.decay_max", inv.decay_max),
+            ("invariants.power_church_k", inv.power_church_k),
+            ("invariants.fear_max", inv.fear_max),
+            ("invariants.bioload_max_site", inv.bioload_max_site),
+            ("spectral.death_high", self.spectral.death_high),
+            ("spectral.decay_high", self.spectral.decay_high),
+            ("spectral.habit_high", self.spectral.habit_high),
+            ("spectral.fear_high_band", self.spectral.fear_high_band),
+            ("spectral.bioload_max_site", self.spectral.bioload_max_site),
+            ("vocab.power_church_k", self.vocab.power_church_k),
+            ("vocab.bioload_high", self.vocab.bioload_high),
+            ("vocab.tecr_high", self.vocab.tecr_high),
+            ("vocab.fear_max_safe", self.vocab.fear_max_safe),
+        ];
+        if let Some(global) = &self.global {
+            ceilings.push(("global.power_church_ratio", global.power_church_ratio));
+            ceilings.push(("global.bioload_max_total", global.bioload_max_total));
+            ceilings.push(("global.fear_max_mean", global.fear_max_mean));
+        }
+        for (field, value) in ceilings {
+            if value < 0.0 || !value.is_finite() {
+                return Err(DoctrineError::NegativeCeiling { field, value });
+            }
+        }
+
+        if let Some(transport) = &self.transport {
+            let rates = [
+                (
+                    "transport.pollution_diffusion",
+                    transport.pollution_diffusion,
+                ),
+                ("transport.fear_diffusion", transport.fear_diffusion),
+            ];
+            for (field, value) in rates {
+                if !(0.0..=1.0).contains(&value) {
+                    return Err(DoctrineError::RateOutOfRange { field, value });
+                }
+            }
+            let weights = [
+                ("transport.exposure_local", transport.exposure_local),
+                ("transport.exposure_neighbor", transport.exposure_neighbor),
+            ];
+            for (field, value) in weights {
+                if value < 0.0 || !value.is_finite() {
+                    return Err(DoctrineError::NegativeWeight { field, value });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn sealed(mut self, text: &str) -> Result<Self, DoctrineError> {
+        self.validate()?;
+        self.content_hash = compute_sha256_hash(text.as_bytes());
+        Ok(self)
+    }
+}
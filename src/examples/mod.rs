@@ -0,0 +1 @@
+pub mod phoenix_power_nano;
@@ -0,0 +1,20 @@
+// src/fate_window.rs
+// Fate_Window identity.
+//
+// A Fate_Window is a fixed span of wall time over which diagnostics and
+// synthesis budgets are accounted; its length is the only property the
+// synthesis limits depend on.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FateWindowId {
+    pub id: u64,
+    pub duration_ms: u64,
+}
+
+impl FateWindowId {
+    pub fn new(id: u64, duration_ms: u64) -> Self {
+        Self { id, duration_ms }
+    }
+}
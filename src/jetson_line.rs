@@ -0,0 +1,177 @@
+// src/jetson_line.rs
+// Tick-driven driver for the Jetson-Line that ties tokens.rs, deeds.rs and
+// spectral.rs together into one deterministic simulation loop.
+//
+// Each call to `step` does the same things, always in the same order:
+// - drains the deed requests due at the current tick (FIFO by submission),
+// - runs each one through the ValidationKernel against the WorldLine,
+// - runs the environmental transport step, if one is configured,
+// - checks the world-level invariants, if configured,
+// - evaluates spectral diagnostics over the post-deed world.
+//
+// No randomness and no wall-clock time are used here; identical inputs give
+// identical TickReports.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::deeds::{
+    DeedRequest, InvariantParams, Tick, ValidatedDeed, ValidationKernel, WorldLine,
+};
+use crate::doctrine::Doctrine;
+use crate::spectral::{SpectralAlert, SpectralEngine, SpectralParams, SpectralState};
+use crate::topology::Topology;
+use crate::transport::{transport_step, TransportParams};
+use crate::world_invariants::{GlobalInvariantParams, GlobalViolation, WorldTotals};
+
+/// Everything that happened on the Jetson-Line during one tick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TickReport {
+    pub tick: Tick,
+
+    /// Kernel output for every request processed at this tick, in processing order.
+    pub deeds: Vec<ValidatedDeed>,
+
+    /// Diagnostic-only alerts computed over the world after all deeds were applied.
+    pub alerts: Vec<SpectralAlert>,
+
+    /// Requests the kernel could not resolve (unknown source or target site).
+    pub dropped: Vec<DeedRequest>,
+
+    /// Global invariants the world violates at the end of the tick.
+    #[serde(default)]
+    pub global_violations: Vec<GlobalViolation>,
+
+    /// Content hash of the doctrine the simulation was configured from, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doctrine_hash: Option<String>,
+}
+
+/// Owns the world, the spectral monitor state, a deed queue and the tick counter.
+pub struct JetsonLineSim {
+    pub world: WorldLine,
+    pub spectral_state: SpectralState,
+    kernel: ValidationKernel,
+    spectral: SpectralEngine,
+    queue: VecDeque<DeedRequest>,
+    transport: Option<TransportParams>,
+    tick: Tick,
+}
+
+impl JetsonLineSim {
+    pub fn new(world: WorldLine, invariants: InvariantParams, spectral: SpectralParams) -> Self {
+        let spectral_state = SpectralState::new(world.len());
+        Self {
+            world,
+            spectral_state,
+            kernel: ValidationKernel::new(invariants),
+            spectral: SpectralEngine::new(spectral),
+            queue: VecDeque::new(),
+            transport: None,
+            tick: 0,
+        }
+    }
+
+    /// Simulation whose kernel, spectral thresholds and transport all come
+    /// from `doctrine`; every TickReport and ValidatedDeed carries its hash.
+    pub fn from_doctrine(world: WorldLine, doctrine: &Doctrine) -> Self {
+        let mut sim = Self::new(world, doctrine.invariants, doctrine.spectral);
+        sim.kernel = ValidationKernel::from_doctrine(doctrine);
+        sim.transport = doctrine.transport;
+        sim
+    }
+
+    /// Restrict deed targets and spread spillover according to `topology`.
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.kernel.topology = Some(topology);
+    }
+
+    /// Check world-level invariants after every deed (blocking breaches) and
+    /// report any that still fail at the end of each tick.
+    pub fn set_global_invariants(&mut self, global: GlobalInvariantParams) {
+        self.kernel.global = Some(global);
+    }
+
+    /// Diffuse POLLUTION and FEAR and refresh EXPOSURE after each tick's deeds,
+    /// over the kernel's topology (an open line if none is set).
+    pub fn set_transport(&mut self, params: TransportParams) {
+        self.transport = Some(params);
+    }
+
+    /// Tick that the next call to `step` will process.
+    pub fn tick(&self) -> Tick {
+        self.tick
+    }
+
+    /// Number of requests still waiting in the queue.
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Queue a deed request.
+    ///
+    /// Requests whose `tick` is in the past are processed at the next step;
+    /// requests scheduled for a future tick wait until that tick is reached.
+    pub fn submit(&mut self, req: DeedRequest) {
+        self.queue.push_back(req);
+    }
+
+    /// Advance the simulation by one tick and report what happened.
+    pub fn step(&mut self) -> TickReport {
+        let tick = self.tick;
+
+        let mut due = Vec::new();
+        let mut later = VecDeque::with_capacity(self.queue.len());
+        for req in self.queue.drain(..) {
+            if req.tick <= tick {
+                due.push(req);
+            } else {
+                later.push_back(req);
+            }
+        }
+        self.queue = later;
+
+        let mut deeds = Vec::with_capacity(due.len());
+        let mut dropped = Vec::new();
+        for mut req in due {
+            // Stamp with the tick it was actually processed at.
+            req.tick = tick;
+            match self.kernel.process_deed(&mut self.world, req.clone()) {
+                Some(validated) => deeds.push(validated),
+                None => dropped.push(req),
+            }
+        }
+
+        if let Some(params) = &self.transport {
+            let topology = self.kernel.topology.unwrap_or_else(Topology::line);
+            transport_step(&mut self.world, &topology, params);
+        }
+
+        let global_violations = self
+            .kernel
+            .global
+            .map(|global| global.check(&WorldTotals::of(&self.world)))
+            .unwrap_or_default();
+
+        let (next_spec, alerts) =
+            self.spectral
+                .evaluate_tick(tick, &self.world, &self.spectral_state, &deeds);
+        self.spectral_state = next_spec;
+        self.tick += 1;
+
+        TickReport {
+            tick,
+            deeds,
+            alerts,
+            dropped,
+            global_violations,
+            doctrine_hash: self.kernel.doctrine_hash.clone(),
+        }
+    }
+
+    /// Run `ticks` consecutive steps and collect their reports.
+    pub fn run(&mut self, ticks: u64) -> Vec<TickReport> {
+        (0..ticks).map(|_| self.step()).collect()
+    }
+}
@@ -1,7 +1,9 @@
-use crate::ledger::Ledger;
+use crate::ledger::{DeedEvent, Keystore, Ledger};
 use crate::simulation::TreeOfLifeSnapshot;
-use crate::utils::time::time_discount_factor;
-use chrono::Utc;
+use crate::utils::time::{
+    discount, validate_discount_tau, Clock, InvalidDiscountTau, SystemClock,
+    DEFAULT_DISCOUNT_TAU_SECONDS,
+};
 
 /// Advisory, non-actuating Church-style account view derived from the ledger.[file:1]
 #[derive(Debug, Default, Clone)]
@@ -17,12 +19,65 @@ pub struct ChurchAccountState {
 impl ChurchAccountState {
     /// Compute an advisory rights view from the DeedEvent ledger, never mutating external state.[file:1]
     pub fn compute_from_ledger(ledger: &Ledger, actor_id: &str) -> Option<Self> {
-        let events = ledger.events_for_actor(actor_id);
-        if events.is_empty() {
-            return None;
-        }
+        Self::compute_from_events(
+            ledger.events_for_actor(actor_id),
+            SystemClock.now(),
+            DEFAULT_DISCOUNT_TAU_SECONDS,
+        )
+    }
+
+    /// Rights view "as of" `clock.now()`, discounting event ages with time constant `tau_seconds`.
+    ///
+    /// With a `FixedClock` the same ledger always yields the same score.
+    pub fn compute_from_ledger_with_clock(
+        ledger: &Ledger,
+        actor_id: &str,
+        clock: &dyn Clock,
+        tau_seconds: f64,
+    ) -> Result<Option<Self>, InvalidDiscountTau> {
+        let tau_seconds = validate_discount_tau(tau_seconds)?;
+        Ok(Self::compute_from_events(
+            ledger.events_for_actor(actor_id),
+            clock.now(),
+            tau_seconds,
+        ))
+    }
+
+    /// Same as `compute_from_ledger`, but only counts events carrying a valid
+    /// signature from a key in `keys`; unsigned or wrongly signed events are ignored.
+    pub fn compute_from_ledger_signed(
+        ledger: &Ledger,
+        actor_id: &str,
+        keys: &dyn Keystore,
+    ) -> Option<Self> {
+        let events = ledger
+            .events_for_actor(actor_id)
+            .filter(|e| e.has_valid_signature(keys));
+        Self::compute_from_events(events, SystemClock.now(), DEFAULT_DISCOUNT_TAU_SECONDS)
+    }
+
+    /// Signed-only rights view "as of" `clock.now()` with time constant `tau_seconds`.
+    pub fn compute_from_ledger_signed_with_clock(
+        ledger: &Ledger,
+        actor_id: &str,
+        keys: &dyn Keystore,
+        clock: &dyn Clock,
+        tau_seconds: f64,
+    ) -> Result<Option<Self>, InvalidDiscountTau> {
+        let tau_seconds = validate_discount_tau(tau_seconds)?;
+        let events = ledger
+            .events_for_actor(actor_id)
+            .filter(|e| e.has_valid_signature(keys));
+        Ok(Self::compute_from_events(events, clock.now(), tau_seconds))
+    }
 
-        let now = Utc::now().timestamp() as u64;
+    fn compute_from_events<'a>(
+        events: impl Iterator<Item = &'a DeedEvent>,
+        now: u64,
+        tau_seconds: f64,
+    ) -> Option<Self> {
+        let mut events = events.peekable();
+        events.peek()?;
 
         let mut good_deeds = 0.0;
         let mut harm_flags = 0u32;
@@ -31,7 +86,7 @@ impl ChurchAccountState {
 
         for event in events {
             let age = now.saturating_sub(event.timestamp);
-            let discount = time_discount_factor(age);
+            let discount = discount(age, tau_seconds);
 
             if event.is_good_deed() {
                 good_deeds += discount;
@@ -1,3 +1,5 @@
+use crate::ledger::DeedSignature;
+use crate::utils::crypto::compute_sha256_hash;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -6,8 +8,11 @@ use serde_json::Value;
 pub struct DeedEvent {
     pub event_id: String,
     pub timestamp: u64,
+    /// Simulation cycle that produced the event, when it came from a simulator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick: Option<u64>,
     pub prev_hash: String,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub self_hash: String,
     pub actor_id: String,
     pub target_ids: Vec<String>,
@@ -16,9 +21,18 @@ pub struct DeedEvent {
     pub context_json: Value,
     pub ethics_flags: Vec<String>,
     pub life_harm_flag: bool,
+    /// Optional signature over `self_hash`; like `self_hash`, never part of the hashed content.
+    #[serde(default, skip_serializing)]
+    pub signature: Option<DeedSignature>,
 }
 
 impl DeedEvent {
+    /// SHA-256 over the serialized event; `self_hash` and `signature` are never part of the input.
+    pub fn compute_hash(&self) -> String {
+        let serialized = serde_json::to_string(self).expect("serialize deed");
+        compute_sha256_hash(serialized.as_bytes())
+    }
+
     pub fn is_good_deed(&self) -> bool {
         !self.life_harm_flag
             && self.ethics_flags.is_empty()
@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+/// Reasons `Ledger::append` refuses an event; the ledger is left unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LedgerError {
+    #[error("event {event_id}: prev_hash {prev_hash:?} does not match ledger head {expected:?}")]
+    BrokenChain {
+        event_id: String,
+        prev_hash: String,
+        expected: String,
+    },
+
+    #[error("event {event_id}: event_id already present in ledger")]
+    DuplicateEventId { event_id: String },
+
+    #[error(
+        "event {event_id}: timestamp {timestamp} is earlier than ledger head {last_timestamp}"
+    )]
+    NonMonotonicTimestamp {
+        event_id: String,
+        timestamp: u64,
+        last_timestamp: u64,
+    },
+
+    #[error("event {event_id}: self_hash is empty")]
+    EmptyHash { event_id: String },
+
+    #[error("event {event_id}: no signing key for {signer_id}")]
+    MissingSigningKey { event_id: String, signer_id: String },
+
+    #[error("event {event_id}: {signer_id} may not sign for actor {actor_id}")]
+    UnauthorizedSigner {
+        event_id: String,
+        signer_id: String,
+        actor_id: String,
+    },
+}
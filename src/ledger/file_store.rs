@@ -0,0 +1,135 @@
+use crate::ledger::{verify_chain, ChainBreakKind, DeedEvent, DeedSignature, Ledger, LedgerError};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// One JSON Lines record: the event plus the hash (and optional signature) it was appended under.
+#[derive(Serialize, Deserialize)]
+struct StoredDeedEvent {
+    self_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<DeedSignature>,
+    event: DeedEvent,
+}
+
+/// Errors raised while opening or appending to an on-disk ledger.
+#[derive(Debug, Error)]
+pub enum LedgerStoreError {
+    #[error("ledger file IO failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Ledger(#[from] LedgerError),
+
+    #[error("ledger line {line}: malformed record: {source}")]
+    Malformed {
+        line: usize,
+        source: serde_json::Error,
+    },
+
+    #[error("ledger line {line}: stored self_hash does not match event content")]
+    HashMismatch { line: usize },
+
+    #[error("ledger line {line}: prev_hash does not link to the previous event")]
+    BrokenLink { line: usize },
+
+    #[error("ledger line {line}: duplicate event_id")]
+    DuplicateEventId { line: usize },
+}
+
+/// Append-only JSON Lines backend for the observer Ledger.
+///
+/// Every line is written once and never rewritten. Opening a file replays and
+/// re-verifies the whole hash chain, so a tampered file is refused up front.
+pub struct FileLedger {
+    path: PathBuf,
+    file: File,
+    ledger: Ledger,
+}
+
+impl FileLedger {
+    /// Open `path`, creating it if missing, and load every event after verification.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, LedgerStoreError> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&path)?;
+
+        let mut events = Vec::new();
+        let mut line_numbers = Vec::new();
+        for (idx, line) in BufReader::new(&file).lines().enumerate() {
+            let line_no = idx + 1;
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let stored: StoredDeedEvent =
+                serde_json::from_str(&line).map_err(|source| LedgerStoreError::Malformed {
+                    line: line_no,
+                    source,
+                })?;
+            let mut event = stored.event;
+            event.self_hash = stored.self_hash;
+            event.signature = stored.signature;
+            events.push(event);
+            line_numbers.push(line_no);
+        }
+
+        if let Some(chain_break) = verify_chain(&events).first_break {
+            let line = line_numbers[chain_break.index];
+            return Err(match chain_break.kind {
+                ChainBreakKind::HashMismatch => LedgerStoreError::HashMismatch { line },
+                ChainBreakKind::BrokenLink => LedgerStoreError::BrokenLink { line },
+                ChainBreakKind::DuplicateEventId => LedgerStoreError::DuplicateEventId { line },
+                ChainBreakKind::InvalidSignature => {
+                    unreachable!("signatures are not checked on load")
+                }
+            });
+        }
+
+        let mut ledger = Ledger::new();
+        for event in events {
+            ledger.append(event)?;
+        }
+
+        Ok(Self { path, file, ledger })
+    }
+
+    /// Append one event to disk and then to memory.
+    ///
+    /// The event is checked against the chain first, and the in-memory ledger
+    /// only changes once its line is flushed, so memory never runs ahead of
+    /// the file.
+    pub fn append(&mut self, event: DeedEvent) -> Result<(), LedgerStoreError> {
+        self.ledger.check_append(&event)?;
+        let stored = StoredDeedEvent {
+            self_hash: event.self_hash.clone(),
+            signature: event.signature.clone(),
+            event,
+        };
+        let mut line = serde_json::to_string(&stored).expect("serialize deed");
+        line.push('\n');
+
+        self.file.write_all(line.as_bytes())?;
+        self.file.flush()?;
+        self.ledger.push(stored.event);
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    pub fn into_ledger(self) -> Ledger {
+        self.ledger
+    }
+}
@@ -0,0 +1,152 @@
+use crate::ledger::{DeedEvent, Ledger};
+use crate::utils::crypto::compute_sha256_hash;
+use serde::{Deserialize, Serialize};
+
+/// Merkle root over one fixed-size batch of consecutive DeedEvents.
+///
+/// Checkpoints are local, offline records; anchoring a root to any external
+/// chain is the job of a separate adapter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleCheckpoint {
+    pub batch_index: usize,
+    /// Ledger index of the first event in the batch.
+    pub start_index: usize,
+    /// Ledger index one past the last event in the batch.
+    pub end_index: usize,
+    pub root: String,
+}
+
+/// Which side of the running hash a sibling sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SiblingSide {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofStep {
+    pub sibling: String,
+    pub side: SiblingSide,
+}
+
+/// Everything a third party needs, besides the event itself, to confirm the
+/// event is included under a checkpoint root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub batch_index: usize,
+    pub event_id: String,
+    pub self_hash: String,
+    pub path: Vec<ProofStep>,
+}
+
+impl InclusionProof {
+    /// Check that `event` hashes to the proven leaf and that the path leads to `root`.
+    pub fn verify(&self, event: &DeedEvent, root: &str) -> bool {
+        if event.event_id != self.event_id || event.compute_hash() != self.self_hash {
+            return false;
+        }
+        let mut acc = leaf_hash(&self.self_hash);
+        for step in &self.path {
+            acc = match step.side {
+                SiblingSide::Left => node_hash(&step.sibling, &acc),
+                SiblingSide::Right => node_hash(&acc, &step.sibling),
+            };
+        }
+        acc == root
+    }
+}
+
+// Leaves and inner nodes are domain-separated so a leaf can never pose as a node.
+fn leaf_hash(self_hash: &str) -> String {
+    compute_sha256_hash(format!("leaf:{}", self_hash).as_bytes())
+}
+
+fn node_hash(left: &str, right: &str) -> String {
+    compute_sha256_hash(format!("node:{}:{}", left, right).as_bytes())
+}
+
+/// All tree levels, leaves first. An unpaired node is carried up unchanged
+/// rather than duplicated, avoiding the duplicate-last-leaf root collision.
+fn merkle_levels(events: &[DeedEvent]) -> Vec<Vec<String>> {
+    let mut levels = vec![events
+        .iter()
+        .map(|e| leaf_hash(&e.self_hash))
+        .collect::<Vec<_>>()];
+
+    while levels.last().is_some_and(|level| level.len() > 1) {
+        let level = levels.last().expect("non-empty levels");
+        let next = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => node_hash(left, right),
+                [single] => single.clone(),
+                _ => unreachable!("chunks(2) yields one or two items"),
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+/// Merkle root of a batch of events; `None` for an empty batch.
+pub fn merkle_root(events: &[DeedEvent]) -> Option<String> {
+    merkle_levels(events).pop()?.pop()
+}
+
+impl Ledger {
+    /// Checkpoint every complete batch of `batch_size` events.
+    ///
+    /// A trailing partial batch is not checkpointed until it fills up.
+    pub fn checkpoints(&self, batch_size: usize) -> Vec<MerkleCheckpoint> {
+        if batch_size == 0 {
+            return Vec::new();
+        }
+        self.all_events()
+            .chunks_exact(batch_size)
+            .enumerate()
+            .map(|(batch_index, batch)| MerkleCheckpoint {
+                batch_index,
+                start_index: batch_index * batch_size,
+                end_index: (batch_index + 1) * batch_size,
+                root: merkle_root(batch).expect("non-empty batch"),
+            })
+            .collect()
+    }
+
+    /// Inclusion proof for the event at `index` within its checkpointed batch.
+    ///
+    /// Returns `None` if the index is out of range or its batch is still partial.
+    pub fn inclusion_proof(&self, index: usize, batch_size: usize) -> Option<InclusionProof> {
+        if batch_size == 0 {
+            return None;
+        }
+        let batch_index = index / batch_size;
+        let start = batch_index * batch_size;
+        let batch = self.all_events().get(start..start + batch_size)?;
+        let event = &batch[index - start];
+
+        let mut path = Vec::new();
+        let mut pos = index - start;
+        for level in merkle_levels(batch).iter().filter(|level| level.len() > 1) {
+            let sibling = pos ^ 1;
+            if let Some(hash) = level.get(sibling) {
+                path.push(ProofStep {
+                    sibling: hash.clone(),
+                    side: if sibling < pos {
+                        SiblingSide::Left
+                    } else {
+                        SiblingSide::Right
+                    },
+                });
+            }
+            pos /= 2;
+        }
+
+        Some(InclusionProof {
+            batch_index,
+            event_id: event.event_id.clone(),
+            self_hash: event.self_hash.clone(),
+            path,
+        })
+    }
+}
@@ -1,43 +1,154 @@
 mod deed_event;
 mod account;
+mod error;
+mod file_store;
+mod merkle;
+mod query;
+mod signing;
+mod verify;
+
+use query::LedgerIndex;
 
 pub use account::ChurchAccountState;
 pub use deed_event::DeedEvent;
+pub use error::LedgerError;
+pub use file_store::{FileLedger, LedgerStoreError};
+pub use merkle::{merkle_root, InclusionProof, MerkleCheckpoint, ProofStep, SiblingSide};
+pub use query::LedgerQuery;
+pub use signing::{DeedSignature, InMemoryKeystore, Keystore};
+pub use verify::{
+    verify_chain, verify_chain_from, verify_chain_with_keys, ChainBreak, ChainBreakKind,
+    LedgerVerification, GENESIS_PREV_HASH,
+};
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct Ledger {
     events: Vec<DeedEvent>,
     last_hash: String,
+    index: LedgerIndex,
 }
 
 impl Ledger {
     pub fn new() -> Self {
         Self {
             events: Vec::new(),
-            last_hash: String::new(),
+            last_hash: GENESIS_PREV_HASH.to_string(),
+            index: LedgerIndex::default(),
         }
     }
 
-    pub fn append(&mut self, event: DeedEvent) {
-        if !self.events.is_empty() && event.prev_hash != self.last_hash {
-            panic!("Invalid prev_hash for DeedEvent");
+    /// Append one event if it extends the chain; on error the ledger is unchanged.
+    pub fn append(&mut self, event: DeedEvent) -> Result<(), LedgerError> {
+        self.check_append(&event)?;
+        self.push(event);
+        Ok(())
+    }
+
+    /// Whether `append` would accept `event`, without appending it.
+    fn check_append(&self, event: &DeedEvent) -> Result<(), LedgerError> {
+        if event.self_hash.is_empty() {
+            return Err(LedgerError::EmptyHash {
+                event_id: event.event_id.clone(),
+            });
+        }
+        if event.prev_hash != self.last_hash {
+            return Err(LedgerError::BrokenChain {
+                event_id: event.event_id.clone(),
+                prev_hash: event.prev_hash.clone(),
+                expected: self.last_hash.clone(),
+            });
+        }
+        if let Some(last) = self.events.last() {
+            if event.timestamp < last.timestamp {
+                return Err(LedgerError::NonMonotonicTimestamp {
+                    event_id: event.event_id.clone(),
+                    timestamp: event.timestamp,
+                    last_timestamp: last.timestamp,
+                });
+            }
         }
+        if self.index.by_event_id.contains_key(&event.event_id) {
+            return Err(LedgerError::DuplicateEventId {
+                event_id: event.event_id.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Append an event `check_append` accepted.
+    fn push(&mut self, event: DeedEvent) {
+        self.index.insert(self.events.len(), &event);
         self.last_hash = event.self_hash.clone();
         self.events.push(event);
     }
 
+    /// Sign `event` with `signer_id`'s key from `keys`, then append it.
+    ///
+    /// `signer_id` must be the event's actor or an observer `keys` allows for it.
+    pub fn append_signed(
+        &mut self,
+        mut event: DeedEvent,
+        signer_id: &str,
+        keys: &dyn Keystore,
+    ) -> Result<(), LedgerError> {
+        if !event.sign_with(signer_id, keys) {
+            return Err(LedgerError::MissingSigningKey {
+                event_id: event.event_id,
+                signer_id: signer_id.to_string(),
+            });
+        }
+        if !keys.may_sign_for(signer_id, &event.actor_id) {
+            return Err(LedgerError::UnauthorizedSigner {
+                event_id: event.event_id,
+                signer_id: signer_id.to_string(),
+                actor_id: event.actor_id,
+            });
+        }
+        self.append(event)
+    }
+
     pub fn last_hash(&self) -> &str {
         &self.last_hash
     }
 
-    pub fn events_for_actor(&self, actor_id: &str) -> Vec<&DeedEvent> {
-        self.events
-            .iter()
-            .filter(|e| e.actor_id == actor_id)
-            .collect()
+    /// Indexed shorthand for `query().actor(actor_id).iter()`.
+    pub fn events_for_actor<'a>(
+        &'a self,
+        actor_id: &'a str,
+    ) -> impl Iterator<Item = &'a DeedEvent> + 'a {
+        self.index
+            .by_actor
+            .get(actor_id)
+            .into_iter()
+            .flatten()
+            .map(move |&pos| &self.events[pos])
+    }
+
+    /// Start an indexed query over this ledger.
+    pub fn query(&self) -> LedgerQuery<'_> {
+        LedgerQuery::new(self)
+    }
+
+    /// Look up an event by its `event_id`.
+    pub fn get(&self, event_id: &str) -> Option<&DeedEvent> {
+        self.index
+            .by_event_id
+            .get(event_id)
+            .map(|&pos| &self.events[pos])
     }
 
     pub fn all_events(&self) -> &[DeedEvent] {
         &self.events
     }
+
+    /// Re-walk the whole chain: recompute hashes, check links and event id uniqueness.
+    pub fn verify(&self) -> LedgerVerification {
+        verify_chain(&self.events)
+    }
+
+    /// Like `verify`, and additionally reject any signed event whose signature
+    /// does not check out against `keys`. Unsigned events are not breaks.
+    pub fn verify_with_keys(&self, keys: &dyn Keystore) -> LedgerVerification {
+        verify_chain_with_keys(&self.events, keys)
+    }
 }
@@ -0,0 +1,184 @@
+use crate::ledger::{DeedEvent, Ledger};
+use std::collections::HashMap;
+
+/// Secondary indexes over ledger positions, maintained on every append.
+///
+/// Each posting list is in ascending ledger order because events are only
+/// ever pushed to the end.
+#[derive(Default, Debug, Clone)]
+pub(crate) struct LedgerIndex {
+    pub(crate) by_event_id: HashMap<String, usize>,
+    pub(crate) by_actor: HashMap<String, Vec<usize>>,
+    by_target: HashMap<String, Vec<usize>>,
+    by_deed_type: HashMap<String, Vec<usize>>,
+    by_tag: HashMap<String, Vec<usize>>,
+    by_ethics_flag: HashMap<String, Vec<usize>>,
+}
+
+impl LedgerIndex {
+    pub(crate) fn insert(&mut self, pos: usize, event: &DeedEvent) {
+        self.by_event_id.insert(event.event_id.clone(), pos);
+        push(&mut self.by_actor, &event.actor_id, pos);
+        push(&mut self.by_deed_type, &event.deed_type, pos);
+        for target in &event.target_ids {
+            push(&mut self.by_target, target, pos);
+        }
+        for tag in &event.tags {
+            push(&mut self.by_tag, tag, pos);
+        }
+        for flag in &event.ethics_flags {
+            push(&mut self.by_ethics_flag, flag, pos);
+        }
+    }
+}
+
+fn push(index: &mut HashMap<String, Vec<usize>>, key: &str, pos: usize) {
+    let postings = index.entry(key.to_string()).or_default();
+    // An event listing the same target/tag twice is indexed once.
+    if postings.last() != Some(&pos) {
+        postings.push(pos);
+    }
+}
+
+/// Builder for indexed ledger lookups; all filters are ANDed together.
+///
+/// Created with `Ledger::query()`; nothing is evaluated until `iter()`.
+#[derive(Debug, Clone)]
+pub struct LedgerQuery<'a> {
+    ledger: &'a Ledger,
+    actor: Option<&'a str>,
+    target: Option<&'a str>,
+    deed_type: Option<&'a str>,
+    tags: Vec<&'a str>,
+    ethics_flags: Vec<&'a str>,
+    since: Option<u64>,
+    until: Option<u64>,
+}
+
+impl<'a> LedgerQuery<'a> {
+    pub(crate) fn new(ledger: &'a Ledger) -> Self {
+        Self {
+            ledger,
+            actor: None,
+            target: None,
+            deed_type: None,
+            tags: Vec::new(),
+            ethics_flags: Vec::new(),
+            since: None,
+            until: None,
+        }
+    }
+
+    pub fn actor(mut self, actor_id: &'a str) -> Self {
+        self.actor = Some(actor_id);
+        self
+    }
+
+    pub fn target(mut self, target_id: &'a str) -> Self {
+        self.target = Some(target_id);
+        self
+    }
+
+    pub fn deed_type(mut self, deed_type: &'a str) -> Self {
+        self.deed_type = Some(deed_type);
+        self
+    }
+
+    /// Require `tag`; may be called repeatedly to require several tags.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Require `flag` in `ethics_flags`; may be called repeatedly.
+    pub fn ethics_flag(mut self, flag: &'a str) -> Self {
+        self.ethics_flags.push(flag);
+        self
+    }
+
+    /// Only events with `timestamp >= since`.
+    pub fn since(mut self, since: u64) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Only events with `timestamp < until`.
+    pub fn until(mut self, until: u64) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Matching events in ledger order.
+    ///
+    /// Walks the shortest posting list among the indexed filters and checks the
+    /// rest per event; the time window is a binary search, since the ledger
+    /// rejects non-monotonic timestamps.
+    pub fn iter(&self) -> impl Iterator<Item = &'a DeedEvent> + '_ {
+        let events = self.ledger.all_events();
+        let lo = self
+            .since
+            .map_or(0, |t| events.partition_point(|e| e.timestamp < t));
+        let hi = self
+            .until
+            .map_or(events.len(), |t| {
+                events.partition_point(|e| e.timestamp < t)
+            })
+            .max(lo);
+
+        let index = &self.ledger.index;
+        let mut lists = Vec::new();
+        let mut missing_key = false;
+        let mut lookup = |map: &'a HashMap<String, Vec<usize>>, key: &str| match map.get(key) {
+            Some(list) => lists.push(list.as_slice()),
+            None => missing_key = true,
+        };
+        if let Some(actor) = self.actor {
+            lookup(&index.by_actor, actor);
+        }
+        if let Some(target) = self.target {
+            lookup(&index.by_target, target);
+        }
+        if let Some(deed_type) = self.deed_type {
+            lookup(&index.by_deed_type, deed_type);
+        }
+        for tag in &self.tags {
+            lookup(&index.by_tag, tag);
+        }
+        for flag in &self.ethics_flags {
+            lookup(&index.by_ethics_flag, flag);
+        }
+
+        let candidates: Box<dyn Iterator<Item = usize> + '_> = if missing_key {
+            Box::new(std::iter::empty())
+        } else {
+            match lists.into_iter().min_by_key(|list| list.len()) {
+                Some(list) => {
+                    let start = list.partition_point(|&pos| pos < lo);
+                    let end = list.partition_point(|&pos| pos < hi);
+                    Box::new(list[start..end].iter().copied())
+                }
+                None => Box::new(lo..hi),
+            }
+        };
+
+        candidates
+            .map(move |pos| &events[pos])
+            .filter(move |event| self.matches(event))
+    }
+
+    fn matches(&self, event: &DeedEvent) -> bool {
+        self.actor.is_none_or(|a| event.actor_id == a)
+            && self
+                .target
+                .is_none_or(|t| event.target_ids.iter().any(|id| id == t))
+            && self.deed_type.is_none_or(|d| event.deed_type == d)
+            && self
+                .tags
+                .iter()
+                .all(|t| event.tags.iter().any(|tag| tag == t))
+            && self
+                .ethics_flags
+                .iter()
+                .all(|f| event.ethics_flags.iter().any(|flag| flag == f))
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ledger::DeedEvent;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Ed25519 signature over a DeedEvent's `self_hash`, made by an actor or observer key.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeedSignature {
+    pub signer_id: String,
+    /// Hex-encoded 64-byte Ed25519 signature.
+    pub signature: String,
+}
+
+/// Local source of signing and verifying keys, addressed by actor/observer id.
+///
+/// Verifiers only need `verifying_key`; a public-key-only store returns `None` from `sign`.
+pub trait Keystore {
+    fn sign(&self, signer_id: &str, message: &[u8]) -> Option<Signature>;
+    fn verifying_key(&self, signer_id: &str) -> Option<VerifyingKey>;
+
+    /// Whether `signer_id` may sign deeds attributed to `actor_id`.
+    ///
+    /// By default an actor only signs for itself; stores with observer keys
+    /// override this with an explicit allow-list.
+    fn may_sign_for(&self, signer_id: &str, actor_id: &str) -> bool {
+        signer_id == actor_id
+    }
+}
+
+/// In-process keystore for sandbox runs and tests; keys never leave memory.
+#[derive(Default)]
+pub struct InMemoryKeystore {
+    keys: HashMap<String, SigningKey>,
+    /// actor id -> observer ids allowed to sign on its behalf.
+    observers: HashMap<String, HashSet<String>>,
+}
+
+impl InMemoryKeystore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate a fresh key for `signer_id` from OS entropy and return its public half.
+    pub fn generate(&mut self, signer_id: &str) -> VerifyingKey {
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        self.insert(signer_id, SigningKey::from_bytes(&secret))
+    }
+
+    pub fn insert(&mut self, signer_id: &str, key: SigningKey) -> VerifyingKey {
+        let verifying = key.verifying_key();
+        self.keys.insert(signer_id.to_string(), key);
+        verifying
+    }
+
+    /// Let `observer_id` co-sign deeds attributed to `actor_id`.
+    pub fn allow_observer(&mut self, actor_id: &str, observer_id: &str) {
+        self.observers
+            .entry(actor_id.to_string())
+            .or_default()
+            .insert(observer_id.to_string());
+    }
+}
+
+impl Keystore for InMemoryKeystore {
+    fn sign(&self, signer_id: &str, message: &[u8]) -> Option<Signature> {
+        self.keys.get(signer_id).map(|key| key.sign(message))
+    }
+
+    fn verifying_key(&self, signer_id: &str) -> Option<VerifyingKey> {
+        self.keys.get(signer_id).map(SigningKey::verifying_key)
+    }
+
+    fn may_sign_for(&self, signer_id: &str, actor_id: &str) -> bool {
+        signer_id == actor_id
+            || self
+                .observers
+                .get(actor_id)
+                .is_some_and(|allowed| allowed.contains(signer_id))
+    }
+}
+
+impl DeedEvent {
+    /// Attach a signature by `signer_id` over the current `self_hash`.
+    ///
+    /// Returns `false` (and leaves the event unsigned) if the keystore has no key for `signer_id`.
+    pub fn sign_with(&mut self, signer_id: &str, keys: &dyn Keystore) -> bool {
+        match keys.sign(signer_id, self.self_hash.as_bytes()) {
+            Some(sig) => {
+                self.signature = Some(DeedSignature {
+                    signer_id: signer_id.to_string(),
+                    signature: hex::encode(sig.to_bytes()),
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// True only for a signed event whose signer may sign for `actor_id` and
+    /// whose signature checks out against the signer's key in `keys`.
+    /// Unsigned events are never valid.
+    pub fn has_valid_signature(&self, keys: &dyn Keystore) -> bool {
+        let Some(sig) = &self.signature else {
+            return false;
+        };
+        if !keys.may_sign_for(&sig.signer_id, &self.actor_id) {
+            return false;
+        }
+        let Some(key) = keys.verifying_key(&sig.signer_id) else {
+            return false;
+        };
+        let Ok(bytes) = hex::decode(&sig.signature) else {
+            return false;
+        };
+        let Ok(signature) = Signature::from_slice(&bytes) else {
+            return false;
+        };
+        key.verify(self.self_hash.as_bytes(), &signature).is_ok()
+    }
+}
@@ -0,0 +1,119 @@
+use crate::ledger::{DeedEvent, Keystore};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+/// `prev_hash` of the first event in a ledger that starts from nothing.
+pub const GENESIS_PREV_HASH: &str = "";
+
+/// Why a DeedEvent fails chain verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChainBreakKind {
+    /// Recomputed SHA-256 of the event differs from its `self_hash`.
+    HashMismatch,
+    /// `prev_hash` does not equal the previous event's `self_hash` (or, for
+    /// the first event, the genesis sentinel or checkpoint anchor).
+    BrokenLink,
+    /// `event_id` was already used by an earlier event.
+    DuplicateEventId,
+    /// The event carries a signature that does not verify against the signer's key,
+    /// or whose signer may not sign for the event's actor.
+    InvalidSignature,
+}
+
+impl fmt::Display for ChainBreakKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainBreakKind::HashMismatch => write!(f, "self_hash does not match event content"),
+            ChainBreakKind::BrokenLink => {
+                write!(f, "prev_hash does not link to the previous event")
+            }
+            ChainBreakKind::DuplicateEventId => write!(f, "duplicate event_id"),
+            ChainBreakKind::InvalidSignature => write!(f, "signature does not verify"),
+        }
+    }
+}
+
+/// First point at which the chain stops being trustworthy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainBreak {
+    pub index: usize,
+    pub event_id: String,
+    pub kind: ChainBreakKind,
+}
+
+/// Result of re-walking a ledger's hash chain from the first event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerVerification {
+    /// Events checked before the first break (or all of them when intact).
+    pub events_checked: usize,
+    pub first_break: Option<ChainBreak>,
+}
+
+impl LedgerVerification {
+    pub fn is_valid(&self) -> bool {
+        self.first_break.is_none()
+    }
+}
+
+/// Recompute every event hash and check links and id uniqueness, in order.
+///
+/// The first event must link to `GENESIS_PREV_HASH`, so a ledger with a
+/// removed prefix does not verify. Everything before `first_break.index` is
+/// trustworthy; nothing after it is.
+pub fn verify_chain(events: &[DeedEvent]) -> LedgerVerification {
+    walk_chain(events, GENESIS_PREV_HASH, None)
+}
+
+/// `verify_chain` for a segment that continues a trusted prefix whose last
+/// `self_hash` is `anchor`, e.g. the events after a checkpointed batch.
+pub fn verify_chain_from(events: &[DeedEvent], anchor: &str) -> LedgerVerification {
+    walk_chain(events, anchor, None)
+}
+
+/// `verify_chain` plus signature checks for every signed event.
+pub fn verify_chain_with_keys(events: &[DeedEvent], keys: &dyn Keystore) -> LedgerVerification {
+    walk_chain(events, GENESIS_PREV_HASH, Some(keys))
+}
+
+fn walk_chain(
+    events: &[DeedEvent],
+    anchor: &str,
+    keys: Option<&dyn Keystore>,
+) -> LedgerVerification {
+    let mut seen_ids = HashSet::with_capacity(events.len());
+
+    for (index, event) in events.iter().enumerate() {
+        let expected_prev = match index {
+            0 => anchor,
+            _ => events[index - 1].self_hash.as_str(),
+        };
+        let kind = if event.compute_hash() != event.self_hash {
+            Some(ChainBreakKind::HashMismatch)
+        } else if event.prev_hash != expected_prev {
+            Some(ChainBreakKind::BrokenLink)
+        } else if !seen_ids.insert(event.event_id.as_str()) {
+            Some(ChainBreakKind::DuplicateEventId)
+        } else if keys.is_some_and(|k| event.signature.is_some() && !event.has_valid_signature(k)) {
+            Some(ChainBreakKind::InvalidSignature)
+        } else {
+            None
+        };
+
+        if let Some(kind) = kind {
+            return LedgerVerification {
+                events_checked: index,
+                first_break: Some(ChainBreak {
+                    index,
+                    event_id: event.event_id.clone(),
+                    kind,
+                }),
+            };
+        }
+    }
+
+    LedgerVerification {
+        events_checked: events.len(),
+        first_break: None,
+    }
+}
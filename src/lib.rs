@@ -1,3 +1,23 @@
+pub mod ledger;
+pub mod simulation;
+pub mod utils;
+pub mod tokens;
+pub mod deeds;
+pub mod spectral;
+pub mod jetson_line;
+pub mod topology;
+pub mod transport;
+pub mod world_invariants;
+pub mod doctrine;
+pub use aln_core;
+pub use aln_core::roh as roh_model;
+pub mod fate_window;
+pub mod quantum;
+#[path = "tree_of_life/TreeofLife.rs"]
+pub mod tree_of_life;
+pub mod societal;
+pub mod examples;
+
 use serde::{Deserialize, Serialize};
 use ring::digest::{Context, SHA256};
 use chrono::Utc;
@@ -36,6 +56,11 @@ pub struct ArchetypePresenter {
 archetypes: Vec<Archetype>,
 roh_ceiling: f64, // 0.3
 }
+impl Default for ArchetypePresenter {
+fn default() -> Self {
+Self::new()
+}
+}
 impl ArchetypePresenter {
 pub fn new() -> Self {
 ArchetypePresenter {
@@ -115,16 +140,3 @@ let result = presenter.pres_arch("teacher", "preacher", traits, 0.95);
 assert!(result.is_ok());
 }
 }
-// Additional file: src/main.rs (for executable binary)
-fn main() -> Result<(), Box<dyn Error>> {
-let mut presenter = ArchetypePresenter::new();
-let mut traits = HashMap::new();
-traits.insert("LIFEFORCE".to_string(), 0.9);
-let presentation = presenter.pres_arch("mentor", "believer", traits, 0.92)?;
-println!("{}", presentation);
-let tokens = presenter.mint_tkn(100, 0.92);
-println!("Earned: {:?}", tokens);
-let deed_hash = presenter.log_deed("Contributed to ecology NPO");
-println!("Deed hash: {}", deed_hash);
-Ok(())
-}
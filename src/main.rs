@@ -1,11 +1,11 @@
-mod simulation;
-mod ledger;
-mod utils;
-
 use clap::{Parser, Subcommand};
-use crate::simulation::MicroSociety;
-use crate::ledger::ChurchAccountState;
+use microsociety_tree_of_life::ledger::{ChurchAccountState, FileLedger};
+use microsociety_tree_of_life::simulation::{CalendarBehindLedger, MicroSociety, SimCalendar};
+use microsociety_tree_of_life::utils::time::{
+    validate_discount_tau, Clock, FixedClock, SystemClock, DEFAULT_DISCOUNT_TAU_SECONDS,
+};
 use std::error::Error;
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "microsociety_sim")]
@@ -23,8 +23,21 @@ enum Commands {
         cycles: usize,
         #[arg(long)]
         agent_count: usize,
+        /// Seed for a reproducible run; omitted means OS entropy.
+        #[arg(long)]
+        seed: Option<u64>,
+        /// JSON Lines ledger file to continue and append this run's events to.
+        #[arg(long)]
+        ledger: Option<PathBuf>,
+        /// Stamp events from a simulated calendar advancing this many seconds per cycle.
+        #[arg(long)]
+        cycle_seconds: Option<u64>,
+        /// Unix timestamp of cycle 0 on the simulated calendar.
+        #[arg(long, default_value_t = 0)]
+        epoch: u64,
     },
-    /// Compute advisory rights metrics for a given agent ID from a fresh in-memory ledger.
+    /// Compute advisory rights metrics for a given agent ID from a saved ledger,
+    /// or from a fresh in-memory ledger when no file is given.
     ComputeRights {
         #[arg(long)]
         agent_id: String,
@@ -32,6 +45,25 @@ enum Commands {
         agent_count: usize,
         #[arg(long, default_value_t = 10)]
         warmup_cycles: usize,
+        /// Seed for a reproducible warmup; omitted means OS entropy.
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Saved JSON Lines ledger to read instead of warming up a fresh society.
+        #[arg(long)]
+        ledger: Option<PathBuf>,
+        /// Warm up on a simulated calendar advancing this many seconds per cycle.
+        #[arg(long)]
+        cycle_seconds: Option<u64>,
+        /// Unix timestamp of cycle 0 on the simulated calendar.
+        #[arg(long, default_value_t = 0)]
+        epoch: u64,
+        /// Evaluate the score as of this Unix timestamp instead of the last
+        /// event's timestamp.
+        #[arg(long)]
+        as_of: Option<u64>,
+        /// Time constant of the deed-age discount, in seconds; must be positive.
+        #[arg(long, default_value_t = DEFAULT_DISCOUNT_TAU_SECONDS, value_parser = parse_tau)]
+        tau_seconds: f64,
     },
 }
 
@@ -39,10 +71,37 @@ fn main() -> Result<(), Box<dyn Error>> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::SimulateCycles { cycles, agent_count } => {
-            let mut society = MicroSociety::new(agent_count);
+        Commands::SimulateCycles {
+            cycles,
+            agent_count,
+            seed,
+            ledger,
+            cycle_seconds,
+            epoch,
+        } => {
+            let mut society = build_society(agent_count, seed, cycle_seconds, epoch)?;
+            let mut store = ledger.map(FileLedger::open).transpose()?;
+            let already_stored = match &store {
+                Some(store) => {
+                    society.adopt_ledger(store.ledger().clone())?;
+                    store.ledger().all_events().len()
+                }
+                None => 0,
+            };
+
             for _ in 0..cycles {
-                society.simulate_cycle();
+                society.simulate_cycle()?;
+            }
+
+            if let Some(store) = store.as_mut() {
+                for event in &society.ledger.all_events()[already_stored..] {
+                    store.append(event.clone())?;
+                }
+                println!(
+                    "Ledger saved to {} ({} events).",
+                    store.path().display(),
+                    store.ledger().all_events().len()
+                );
             }
             println!(
                 "Simulation completed for {} agents over {} cycles (sandbox-only).",
@@ -53,14 +112,36 @@ fn main() -> Result<(), Box<dyn Error>> {
             agent_id,
             agent_count,
             warmup_cycles,
+            seed,
+            ledger,
+            cycle_seconds,
+            epoch,
+            as_of,
+            tau_seconds,
         } => {
-            let mut society = MicroSociety::new(agent_count);
-            for _ in 0..warmup_cycles {
-                society.simulate_cycle();
-            }
+            let ledger = match ledger {
+                Some(path) => FileLedger::open(path)?.into_ledger(),
+                None => {
+                    let mut society = build_society(agent_count, seed, cycle_seconds, epoch)?;
+                    for _ in 0..warmup_cycles {
+                        society.simulate_cycle()?;
+                    }
+                    society.ledger
+                }
+            };
+            // Score as of the last recorded deed, so a saved ledger and the
+            // warmup that produced it agree whatever clock stamped them.
+            let now = ledger
+                .all_events()
+                .last()
+                .map_or_else(|| SystemClock.now(), |e| e.timestamp);
 
-            if let Some(state) = ChurchAccountState::compute_from_ledger(&society.ledger, &agent_id)
-            {
+            if let Some(state) = ChurchAccountState::compute_from_ledger_with_clock(
+                &ledger,
+                &agent_id,
+                &FixedClock(as_of.unwrap_or(now)),
+                tau_seconds,
+            )? {
                 println!(
                     "Advisory Rights-to-Exist score for {}: {:.3}",
                     agent_id, state.existence_rights_score
@@ -80,3 +161,24 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+fn parse_tau(s: &str) -> Result<f64, String> {
+    let tau: f64 = s.parse().map_err(|e| format!("{e}"))?;
+    validate_discount_tau(tau).map_err(|e| e.to_string())
+}
+
+fn build_society(
+    agent_count: usize,
+    seed: Option<u64>,
+    cycle_seconds: Option<u64>,
+    epoch: u64,
+) -> Result<MicroSociety, CalendarBehindLedger> {
+    let mut society = match seed {
+        Some(seed) => MicroSociety::with_seed(agent_count, seed),
+        None => MicroSociety::new(agent_count),
+    };
+    if let Some(cycle_seconds) = cycle_seconds {
+        society.set_calendar(SimCalendar::new(epoch, cycle_seconds))?;
+    }
+    Ok(society)
+}
@@ -0,0 +1,171 @@
+//! Minimal gate-list IR for planned circuits and the structural metrics the
+//! synthesis gate checks.
+//!
+//! Ops are scheduled as soon as possible: each op lands in the layer after
+//! the latest op on any of its qubits. Barriers add no layer but hold their
+//! qubits to a common frontier, as in OpenQASM.
+
+use std::collections::HashMap;
+
+use crate::fate_window::FateWindowId;
+use crate::quantum::constraints::ProjectedQuantumPlanMetrics;
+use crate::roh_model::RoHScore;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op {
+    /// A unitary gate; more than one qubit makes it entangling.
+    Gate {
+        name: String,
+        qubits: Vec<u32>,
+    },
+    Measure {
+        qubit: u32,
+    },
+    Reset {
+        qubit: u32,
+    },
+    Barrier {
+        qubits: Vec<u32>,
+    },
+}
+
+impl Op {
+    pub fn gate(name: impl Into<String>, qubits: impl Into<Vec<u32>>) -> Self {
+        Op::Gate {
+            name: name.into(),
+            qubits: qubits.into(),
+        }
+    }
+
+    pub fn qubits(&self) -> &[u32] {
+        match self {
+            Op::Gate { qubits, .. } | Op::Barrier { qubits } => qubits,
+            Op::Measure { qubit } | Op::Reset { qubit } => std::slice::from_ref(qubit),
+        }
+    }
+
+    pub fn is_entangling(&self) -> bool {
+        matches!(self, Op::Gate { qubits, .. } if qubits.len() > 1)
+    }
+}
+
+/// One shot of a planned circuit, as an ordered op list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Circuit {
+    pub num_qubits: u32,
+    pub ops: Vec<Op>,
+}
+
+/// Structural metrics of one shot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircuitStats {
+    pub depth: u32,
+    /// Mean over layers of the share of the layer's ops that are entangling.
+    pub entangling_density: f32,
+    /// Measurements per shot.
+    pub measurements: u32,
+}
+
+impl Circuit {
+    pub fn new(num_qubits: u32) -> Self {
+        Self {
+            num_qubits,
+            ops: Vec::new(),
+        }
+    }
+
+    /// Append an op, growing `num_qubits` to cover its operands.
+    pub fn push(&mut self, op: Op) {
+        if let Some(&max) = op.qubits().iter().max() {
+            self.num_qubits = self.num_qubits.max(max + 1);
+        }
+        self.ops.push(op);
+    }
+
+    /// Layer index (0-based) of every non-barrier op, in op order.
+    ///
+    /// The frontier only tracks qubits the ops touch, so a wide register
+    /// costs nothing until it is used.
+    pub fn layers(&self) -> Vec<Option<u32>> {
+        let mut frontier: HashMap<u32, u32> = HashMap::new();
+        self.ops
+            .iter()
+            .map(|op| {
+                let level = op
+                    .qubits()
+                    .iter()
+                    .filter_map(|q| frontier.get(q).copied())
+                    .max()
+                    .unwrap_or(0);
+                let next = match op {
+                    Op::Barrier { .. } => level,
+                    _ => level + 1,
+                };
+                for &q in op.qubits() {
+                    frontier.insert(q, next);
+                }
+                (!matches!(op, Op::Barrier { .. })).then_some(level)
+            })
+            .collect()
+    }
+
+    pub fn stats(&self) -> CircuitStats {
+        let layers = self.layers();
+        let depth = layers.iter().flatten().map(|l| l + 1).max().unwrap_or(0);
+
+        let mut per_layer = vec![(0u32, 0u32); depth as usize];
+        for (op, layer) in self.ops.iter().zip(&layers) {
+            if let Some(layer) = layer {
+                let (entangling, total) = &mut per_layer[*layer as usize];
+                *total += 1;
+                *entangling += u32::from(op.is_entangling());
+            }
+        }
+        let entangling_density = if depth == 0 {
+            0.0
+        } else {
+            per_layer
+                .iter()
+                .map(|&(e, t)| e as f32 / t as f32)
+                .sum::<f32>()
+                / depth as f32
+        };
+
+        CircuitStats {
+            depth,
+            entangling_density,
+            measurements: self
+                .ops
+                .iter()
+                .filter(|op| matches!(op, Op::Measure { .. }))
+                .count() as u32,
+        }
+    }
+}
+
+impl CircuitStats {
+    /// Measurements across a Fate_Window when a shot starts every
+    /// `shot_period_ms`; a window always holds at least one shot.
+    pub fn meas_per_window(&self, fate_window: FateWindowId, shot_period_ms: u64) -> u32 {
+        let shots = (fate_window.duration_ms / shot_period_ms.max(1)).max(1);
+        u32::try_from(shots.saturating_mul(self.measurements.into())).unwrap_or(u32::MAX)
+    }
+
+    /// Combine with the RoH and ROD projections, which come from the
+    /// diagnostic models rather than the circuit.
+    pub fn project(
+        &self,
+        fate_window: FateWindowId,
+        shot_period_ms: u64,
+        projected_roh_after: RoHScore,
+        projected_rod: f32,
+    ) -> ProjectedQuantumPlanMetrics {
+        ProjectedQuantumPlanMetrics {
+            projected_roh_after,
+            projected_rod,
+            circuit_depth: self.depth,
+            entangling_density: self.entangling_density,
+            meas_per_window: self.meas_per_window(fate_window, shot_period_ms),
+        }
+    }
+}
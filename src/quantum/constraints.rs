@@ -1,20 +1,33 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::OnceLock;
+
+use aln::expr::{CmpOp, Expr, ExprError, Value};
+use aln::{Row, Section, Shard};
+use thiserror::Error;
+
 use crate::aln_core::{CapabilityState, JurisdictionTags};
-use crate::roh_model::RoHScore;
 use crate::fate_window::FateWindowId;
+use crate::roh_model::{RoHScore, ROH_CEILING};
+
+/// The QUANTUM-SYNTHESIS-CONSTRAINTS shard shipped with this crate.
+pub const CONSTRAINTS_SHARD: &str = include_str!("../../QUANTUM-SYNTHESIS-CONSTRAINTS.aln");
+
+const SECTION_NAME: &str = "QUANTUM-SYNTHESIS-CONSTRAINTS";
 
 /// Static, read-only constraints for one synthesis run.
 #[derive(Debug, Clone)]
 pub struct QuantumSynthesisConstraints {
-    pub roh_ceiling: f32,              // must be <= 0.30
-    pub rod_budget: f32,               // < 1.0
+    pub roh_ceiling: f32, // must be <= 0.30
+    pub rod_budget: f32,  // < 1.0
     pub max_depth: u32,
-    pub max_entangling_density: f32,   // 0.0–1.0
+    pub max_entangling_density: f32, // 0.0–1.0
     pub max_meas_per_window: u32,
     pub roh_penalty_coeff: f32,
     pub rod_penalty_coeff: f32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SynthViolation {
     RoHCeilingWouldBeExceeded,
     RODBudgetWouldBeExceeded,
@@ -23,7 +36,7 @@ pub enum SynthViolation {
     MeasurementCadenceExceeded,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ProjectedQuantumPlanMetrics {
     pub projected_roh_after: RoHScore, // normalized to 0.30
     pub projected_rod: f32,            // 0.0–1.0
@@ -32,38 +45,609 @@ pub struct ProjectedQuantumPlanMetrics {
     pub meas_per_window: u32,
 }
 
+/// Reasons a constraints shard cannot be decoded, or a baseline cannot
+/// scale it.
+#[derive(Debug, Error)]
+pub enum ConstraintShardError {
+    #[error("shard has no {SECTION_NAME} section")]
+    MissingSection,
+
+    #[error("shard has no FORMULA row for {0}")]
+    MissingFormula(&'static str),
+
+    #[error("line {line}: malformed {kind} row")]
+    MalformedRow { line: usize, kind: &'static str },
+
+    #[error("line {line}: unknown formula function {name}")]
+    UnknownFunction { line: usize, name: String },
+
+    #[error("line {line}: {name} takes ({expected}), shard passes ({found})")]
+    SignatureMismatch {
+        line: usize,
+        name: String,
+        expected: String,
+        found: String,
+    },
+
+    #[error("line {line}: {name} returns {expected}, shard declares {found}")]
+    ReturnTypeMismatch {
+        line: usize,
+        name: String,
+        expected: &'static str,
+        found: String,
+    },
+
+    #[error("line {line}: {name} is not listed in the INPUTS row")]
+    UndeclaredInput { line: usize, name: String },
+
+    #[error("line {line}: invalid constraint: {source}")]
+    InvalidConstraint { line: usize, source: ExprError },
+
+    #[error("line {line}: lower bound on {name} would loosen it; constraints may only tighten")]
+    LooseningConstraint { line: usize, name: String },
+
+    #[error("line {line}: cannot interpret constraint {expr}")]
+    UnsupportedConstraint { line: usize, expr: String },
+
+    #[error("{name} is {value}; strictness must be in (0, 1]")]
+    InvalidStrictness { name: String, value: f32 },
+}
+
+/// Constants the formula functions scale by.
+///
+/// The shard names each formula and its inputs but carries no constants,
+/// so callers supply them here.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SynthesisBaseline {
+    /// Circuit depth allowed to a model-only run with a full ROD budget.
+    pub max_depth: f32,
+    /// Entangling density allowed to a model-only run.
+    pub entangling_density: f32,
+    /// Minimum spacing between mid-circuit measurements, in milliseconds.
+    pub meas_interval_ms: f32,
+    /// Multiplier in (0, 1] on every limit, per capability tier in
+    /// [`CapabilityState::ALL`] order.
+    pub tier_strictness: [f32; 4],
+    /// Further multiplier in (0, 1] under a `neurorights` jurisdiction tag.
+    pub neurorights_strictness: f32,
+}
+
+impl Default for SynthesisBaseline {
+    /// Uncalibrated sandbox values: they come from neither the shard nor a
+    /// doctrine document, only from keeping the tiers ordered. Supply
+    /// measured values through [`load_constraints_with`] before relying on
+    /// the limits.
+    fn default() -> Self {
+        Self {
+            max_depth: 256.0,
+            entangling_density: 0.5,
+            meas_interval_ms: 10.0,
+            tier_strictness: [1.0, 0.75, 0.5, 0.25],
+            neurorights_strictness: 0.8,
+        }
+    }
+}
+
+impl SynthesisBaseline {
+    /// Every strictness multiplier is finite and in (0, 1]. A zero would make
+    /// the penalty coefficients infinite.
+    pub fn validate(&self) -> Result<(), ConstraintShardError> {
+        let tiers = self
+            .tier_strictness
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("tier_strictness[{i}]"), *v));
+        let rights = std::iter::once((
+            "neurorights_strictness".to_string(),
+            self.neurorights_strictness,
+        ));
+        for (name, value) in tiers.chain(rights) {
+            if !(value > 0.0 && value <= 1.0) {
+                return Err(ConstraintShardError::InvalidStrictness { name, value });
+            }
+        }
+        Ok(())
+    }
+
+    /// Tier multiplier, further reduced under a neurorights bundle.
+    fn strictness(&self, cap: CapabilityState, juris: &JurisdictionTags) -> f32 {
+        let rights = if juris.contains("neurorights") {
+            self.neurorights_strictness
+        } else {
+            1.0
+        };
+        self.tier_strictness[cap as usize] * rights
+    }
+}
+
+/// Load-time inputs a formula argument can name.
+struct FormulaInputs<'a> {
+    baseline: &'a SynthesisBaseline,
+    cap: CapabilityState,
+    juris: &'a JurisdictionTags,
+    fate_window: FateWindowId,
+    rod_budget: f32,
+}
+
+/// Rust implementation of one formula function the shard may call.
+struct Builtin {
+    name: &'static str,
+    params: &'static [&'static str],
+    returns: &'static str,
+    eval: fn(&FormulaInputs<'_>) -> f32,
+}
+
+const CAP_JURIS: &[&str] = &["CAPABILITY.STATE", "JURIS.TAGS"];
+
+const BUILTINS: [Builtin; 5] = [
+    Builtin {
+        name: "synth_max_depth",
+        params: &["CAPABILITY.STATE", "JURIS.TAGS", "DIAG.ROD.BUDGET"],
+        returns: "INT",
+        eval: |i| (i.baseline.max_depth * i.strictness() * i.rod_budget).floor(),
+    },
+    Builtin {
+        name: "synth_max_entangling_density",
+        params: CAP_JURIS,
+        returns: "FLOAT",
+        eval: |i| (i.baseline.entangling_density * i.strictness()).clamp(0.0, 1.0),
+    },
+    Builtin {
+        name: "synth_max_measurements",
+        params: &["FATE.WINDOW.DURATION_MS", "DIAG.ROD.BUDGET"],
+        returns: "INT",
+        eval: |i| {
+            (i.fate_window.duration_ms as f32 / i.baseline.meas_interval_ms * i.rod_budget).floor()
+        },
+    },
+    Builtin {
+        name: "roh_penalty_coeff",
+        params: CAP_JURIS,
+        returns: "FLOAT",
+        eval: |i| 1.0 / i.strictness(),
+    },
+    Builtin {
+        name: "rod_penalty_coeff",
+        params: CAP_JURIS,
+        returns: "FLOAT",
+        eval: |i| 0.5 / i.strictness(),
+    },
+];
+
+impl FormulaInputs<'_> {
+    fn strictness(&self) -> f32 {
+        self.baseline.strictness(self.cap, self.juris)
+    }
+}
+
+/// Constraint output each FORMULA row name fills in.
+const FORMULAS: [&str; 5] = [
+    "MAX_DEPTH",
+    "MAX_ENTANGLING_DENSITY",
+    "MAX_MEAS_PER_WINDOW",
+    "ROH_PENALTY_COEFF",
+    "ROD_PENALTY_COEFF",
+];
+
+/// The bundled shard, parsed on first use.
+fn bundled_shard() -> &'static Shard {
+    static SHARD: OnceLock<Shard> = OnceLock::new();
+    SHARD.get_or_init(|| aln::parse(CONSTRAINTS_SHARD).expect("bundled constraints shard parses"))
+}
+
 /// Pure helper; reads QUANTUM-SYNTHESIS-CONSTRAINTS shard, never writes.
+///
+/// Uses the uncalibrated [`SynthesisBaseline::default`].
 pub fn load_constraints(
     cap: CapabilityState,
     juris: &JurisdictionTags,
     fate_window: FateWindowId,
     rod_budget: f32,
 ) -> QuantumSynthesisConstraints {
-    // Implementation: decode the ALN shard row for this (cap, juris, fate_window),
-    // apply synth_max_* formulas, and clamp to RoH ≤ 0.30, ROD < 1.0.
-    unimplemented!()
+    load_constraints_with(
+        &SynthesisBaseline::default(),
+        cap,
+        juris,
+        fate_window,
+        rod_budget,
+    )
+}
+
+/// `load_constraints` with caller-supplied formula constants.
+///
+/// Panics if `baseline` fails [`SynthesisBaseline::validate`].
+pub fn load_constraints_with(
+    baseline: &SynthesisBaseline,
+    cap: CapabilityState,
+    juris: &JurisdictionTags,
+    fate_window: FateWindowId,
+    rod_budget: f32,
+) -> QuantumSynthesisConstraints {
+    load_constraints_from(
+        bundled_shard(),
+        baseline,
+        cap,
+        juris,
+        fate_window,
+        rod_budget,
+    )
+    .unwrap_or_else(|e| panic!("cannot load the bundled constraints shard: {e}"))
+}
+
+/// `load_constraints` against an arbitrary constraints shard.
+///
+/// The RoH ceiling never exceeds [`ROH_CEILING`] and the ROD budget is
+/// always strictly below 1.0, whatever the shard says. CONSTRAINT rows of
+/// the form `<input> <op> <literal>`, with the literal on either side, may
+/// only tighten those two further: an upper bound (`<=`, `<`) clamps, a
+/// lower bound (`>=`, `>`) is rejected. Rows over plan-time values only
+/// (such as RoH monotonicity) are left to the plan checks, and any other
+/// row naming either input is an error. FORMULA rows are dispatched to the
+/// built-in formula functions after checking their arguments and declared
+/// return type.
+pub fn load_constraints_from(
+    shard: &Shard,
+    baseline: &SynthesisBaseline,
+    cap: CapabilityState,
+    juris: &JurisdictionTags,
+    fate_window: FateWindowId,
+    rod_budget: f32,
+) -> Result<QuantumSynthesisConstraints, ConstraintShardError> {
+    baseline.validate()?;
+    let section = shard
+        .section(SECTION_NAME)
+        .ok_or(ConstraintShardError::MissingSection)?;
+
+    let mut roh_ceiling = ROH_CEILING;
+    let mut rod_budget = rod_budget.max(0.0);
+    for row in section.rows_of("CONSTRAINT") {
+        let malformed = ConstraintShardError::MalformedRow {
+            line: row.line,
+            kind: "CONSTRAINT",
+        };
+        let text = row.field(2).ok_or(malformed)?;
+        let expr = Expr::parse(text).map_err(|source| ConstraintShardError::InvalidConstraint {
+            line: row.line,
+            source,
+        })?;
+        let (name, op, bound) = match input_bound(&expr) {
+            ConstraintRow::PlanTime => continue,
+            ConstraintRow::Bound { name, op, bound } => (name, op, bound),
+            ConstraintRow::Unsupported => {
+                return Err(ConstraintShardError::UnsupportedConstraint {
+                    line: row.line,
+                    expr: text.to_string(),
+                })
+            }
+        };
+        if matches!(op, CmpOp::Ge | CmpOp::Gt) {
+            return Err(ConstraintShardError::LooseningConstraint {
+                line: row.line,
+                name: name.to_string(),
+            });
+        }
+        let limit = if name == "ROH.MODEL.CEILING" {
+            &mut roh_ceiling
+        } else {
+            &mut rod_budget
+        };
+        *limit = clamp_to(*limit, op, bound as f32);
+    }
+    // Doctrine limits hold even if the shard omits its rows.
+    let roh_ceiling = roh_ceiling.min(ROH_CEILING);
+    let rod_budget = clamp_to(rod_budget, CmpOp::Lt, 1.0);
+
+    let inputs = declared_inputs(section)?;
+    let formula_inputs = FormulaInputs {
+        baseline,
+        cap,
+        juris,
+        fate_window,
+        rod_budget,
+    };
+    let mut values = [0.0f32; FORMULAS.len()];
+    for (slot, name) in values.iter_mut().zip(FORMULAS) {
+        let row = section
+            .rows_of("FORMULA")
+            .find(|r| r.field(1) == Some(name))
+            .ok_or(ConstraintShardError::MissingFormula(name))?;
+        *slot = eval_formula(row, &inputs, &formula_inputs)?;
+    }
+    let [max_depth, max_entangling_density, max_meas_per_window, roh_penalty_coeff, rod_penalty_coeff] =
+        values;
+
+    Ok(QuantumSynthesisConstraints {
+        roh_ceiling,
+        rod_budget,
+        max_depth: max_depth as u32,
+        max_entangling_density,
+        max_meas_per_window: max_meas_per_window as u32,
+        roh_penalty_coeff,
+        rod_penalty_coeff,
+    })
+}
+
+/// Load-time inputs a CONSTRAINT row may bound.
+const BOUNDED_INPUTS: [&str; 2] = ["ROH.MODEL.CEILING", "DIAG.ROD.BUDGET"];
+
+/// How the loader reads one CONSTRAINT expression.
+enum ConstraintRow<'a> {
+    /// `<input> <op> <bound>` over one of [`BOUNDED_INPUTS`], with an
+    /// ordering operator and the input on the left.
+    Bound {
+        name: &'a str,
+        op: CmpOp,
+        bound: f64,
+    },
+    /// Names none of [`BOUNDED_INPUTS`]; checked against the plan instead.
+    PlanTime,
+    Unsupported,
+}
+
+fn input_bound(expr: &Expr) -> ConstraintRow<'_> {
+    if !names_bounded_input(expr) {
+        return ConstraintRow::PlanTime;
+    }
+    let Expr::Cmp(op, lhs, rhs) = expr else {
+        return ConstraintRow::Unsupported;
+    };
+    let (name, op, bound) = match (&**lhs, &**rhs) {
+        (Expr::Var(name), Expr::Literal(Value::Number(bound))) => (name, *op, *bound),
+        (Expr::Literal(Value::Number(bound)), Expr::Var(name)) => (name, flip(*op), *bound),
+        _ => return ConstraintRow::Unsupported,
+    };
+    if matches!(op, CmpOp::Eq | CmpOp::Ne) {
+        return ConstraintRow::Unsupported;
+    }
+    ConstraintRow::Bound { name, op, bound }
+}
+
+fn names_bounded_input(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(_) => false,
+        Expr::Var(name) => BOUNDED_INPUTS.contains(&name.as_str()),
+        Expr::Not(inner) => names_bounded_input(inner),
+        Expr::And(a, b) | Expr::Or(a, b) | Expr::Cmp(_, a, b) => {
+            names_bounded_input(a) || names_bounded_input(b)
+        }
+    }
+}
+
+/// The operator that keeps `a <op> b` true as `b <flip(op)> a`.
+fn flip(op: CmpOp) -> CmpOp {
+    match op {
+        CmpOp::Lt => CmpOp::Gt,
+        CmpOp::Le => CmpOp::Ge,
+        CmpOp::Gt => CmpOp::Lt,
+        CmpOp::Ge => CmpOp::Le,
+        other => other,
+    }
+}
+
+/// Lower `value` so that the upper bound `value <op> bound` holds; other
+/// operators leave it unchanged.
+fn clamp_to(value: f32, op: CmpOp, bound: f32) -> f32 {
+    match op {
+        CmpOp::Le => value.min(bound),
+        CmpOp::Lt if value >= bound => next_toward(bound, f32::NEG_INFINITY),
+        _ => value,
+    }
+}
+
+/// Adjacent representable f32 from finite `x` in the direction of `toward`.
+fn next_toward(x: f32, toward: f32) -> f32 {
+    if x == 0.0 {
+        let tiny = f32::from_bits(1);
+        return if toward > 0.0 { tiny } else { -tiny };
+    }
+    let bits = x.to_bits();
+    if (toward > x) == (x > 0.0) {
+        f32::from_bits(bits + 1)
+    } else {
+        f32::from_bits(bits - 1)
+    }
+}
+
+fn declared_inputs(section: &Section) -> Result<BTreeSet<&str>, ConstraintShardError> {
+    let row = section
+        .rows_of("INPUTS")
+        .next()
+        .ok_or(ConstraintShardError::MalformedRow {
+            line: section.line,
+            kind: "INPUTS",
+        })?;
+    Ok(row.fields[1..].iter().map(String::as_str).collect())
+}
+
+fn eval_formula(
+    row: &Row,
+    inputs: &BTreeSet<&str>,
+    values: &FormulaInputs<'_>,
+) -> Result<f32, ConstraintShardError> {
+    let malformed = ConstraintShardError::MalformedRow {
+        line: row.line,
+        kind: "FORMULA",
+    };
+    let (declared, call) = match (row.field(2), row.field(3)) {
+        (Some(declared), Some(call)) => (declared, call),
+        _ => return Err(malformed),
+    };
+    let (name, args) = call
+        .strip_suffix(')')
+        .and_then(|c| c.split_once('('))
+        .ok_or(malformed)?;
+    let name = name.trim();
+    let args: Vec<&str> = args
+        .split(',')
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .collect();
+
+    let builtin = BUILTINS.iter().find(|b| b.name == name).ok_or_else(|| {
+        ConstraintShardError::UnknownFunction {
+            line: row.line,
+            name: name.to_string(),
+        }
+    })?;
+    if args != builtin.params {
+        return Err(ConstraintShardError::SignatureMismatch {
+            line: row.line,
+            name: name.to_string(),
+            expected: builtin.params.join(", "),
+            found: args.join(", "),
+        });
+    }
+    if declared != builtin.returns {
+        return Err(ConstraintShardError::ReturnTypeMismatch {
+            line: row.line,
+            name: name.to_string(),
+            expected: builtin.returns,
+            found: declared.to_string(),
+        });
+    }
+    if let Some(arg) = args.iter().find(|a| !inputs.contains(**a)) {
+        return Err(ConstraintShardError::UndeclaredInput {
+            line: row.line,
+            name: arg.to_string(),
+        });
+    }
+    Ok((builtin.eval)(values))
+}
+
+/// One synthesis limit evaluated against a plan.
+///
+/// `slack` is `limit - observed`: how far the plan could still grow before
+/// the limit is hit, negative once it is over. The ROD budget is strict, so
+/// a ROD check with zero slack is already violated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstraintCheck {
+    /// The violation this check reports when it fails.
+    pub violation: SynthViolation,
+    pub limit: f64,
+    pub observed: f64,
+    pub slack: f64,
+    pub satisfied: bool,
+}
+
+impl ConstraintCheck {
+    fn at_most(violation: SynthViolation, limit: f64, observed: f64) -> Self {
+        Self {
+            violation,
+            limit,
+            observed,
+            slack: limit - observed,
+            satisfied: observed <= limit,
+        }
+    }
+
+    fn below(violation: SynthViolation, limit: f64, observed: f64) -> Self {
+        Self {
+            satisfied: observed < limit,
+            ..Self::at_most(violation, limit, observed)
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self.violation {
+            SynthViolation::RoHCeilingWouldBeExceeded => "RoH after",
+            SynthViolation::RODBudgetWouldBeExceeded => "ROD",
+            SynthViolation::DepthLimitExceeded => "circuit depth",
+            SynthViolation::EntanglingDensityExceeded => "entangling density",
+            SynthViolation::MeasurementCadenceExceeded => "measurements per window",
+        }
+    }
+}
+
+impl fmt::Display for ConstraintCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match (self.violation, self.satisfied) {
+            (SynthViolation::RODBudgetWouldBeExceeded, true) => "<",
+            (SynthViolation::RODBudgetWouldBeExceeded, false) => ">=",
+            (_, true) => "<=",
+            (_, false) => ">",
+        };
+        let prec = match self.violation {
+            SynthViolation::DepthLimitExceeded | SynthViolation::MeasurementCadenceExceeded => 0,
+            _ => 3,
+        };
+        write!(
+            f,
+            "{} {:.prec$} {op} limit {:.prec$} (slack {:.prec$})",
+            self.label(),
+            self.observed,
+            self.limit,
+            self.slack
+        )
+    }
+}
+
+/// Every synthesis limit evaluated against one plan, in gate order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstraintReport {
+    pub checks: Vec<ConstraintCheck>,
+}
+
+impl ConstraintReport {
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.satisfied)
+    }
+
+    pub fn violations(&self) -> impl Iterator<Item = &ConstraintCheck> {
+        self.checks.iter().filter(|c| !c.satisfied)
+    }
+
+    pub fn first_violation(&self) -> Option<SynthViolation> {
+        self.violations().next().map(|c| c.violation)
+    }
+}
+
+/// Evaluate a plan against every limit, without stopping at the first
+/// failure.
+pub fn evaluate_plan(
+    constraints: &QuantumSynthesisConstraints,
+    metrics: &ProjectedQuantumPlanMetrics,
+) -> ConstraintReport {
+    use SynthViolation::*;
+    ConstraintReport {
+        checks: vec![
+            ConstraintCheck::at_most(
+                RoHCeilingWouldBeExceeded,
+                constraints.roh_ceiling.into(),
+                metrics.projected_roh_after.value().into(),
+            ),
+            ConstraintCheck::below(
+                RODBudgetWouldBeExceeded,
+                constraints.rod_budget.into(),
+                metrics.projected_rod.into(),
+            ),
+            ConstraintCheck::at_most(
+                DepthLimitExceeded,
+                constraints.max_depth.into(),
+                metrics.circuit_depth.into(),
+            ),
+            ConstraintCheck::at_most(
+                EntanglingDensityExceeded,
+                constraints.max_entangling_density.into(),
+                metrics.entangling_density.into(),
+            ),
+            ConstraintCheck::at_most(
+                MeasurementCadenceExceeded,
+                constraints.max_meas_per_window.into(),
+                metrics.meas_per_window.into(),
+            ),
+        ],
+    }
 }
 
 /// Compile-time gate: called by the quantum circuit synthesizer
-/// before committing a schedule.
+/// before committing a schedule. Reports the first failed limit; use
+/// [`evaluate_plan`] to see all of them.
 pub fn check_plan_against_constraints(
     constraints: &QuantumSynthesisConstraints,
     metrics: &ProjectedQuantumPlanMetrics,
 ) -> Result<(), SynthViolation> {
-    if metrics.projected_roh_after.value() > constraints.roh_ceiling {
-        return Err(SynthViolation::RoHCeilingWouldBeExceeded);
-    }
-    if metrics.projected_rod >= constraints.rod_budget {
-        return Err(SynthViolation::RODBudgetWouldBeExceeded);
-    }
-    if metrics.circuit_depth > constraints.max_depth {
-        return Err(SynthViolation::DepthLimitExceeded);
-    }
-    if metrics.entangling_density > constraints.max_entangling_density {
-        return Err(SynthViolation::EntanglingDensityExceeded);
-    }
-    if metrics.meas_per_window > constraints.max_meas_per_window {
-        return Err(SynthViolation::MeasurementCadenceExceeded);
+    match evaluate_plan(constraints, metrics).first_violation() {
+        Some(violation) => Err(violation),
+        None => Ok(()),
     }
-    Ok(())
 }
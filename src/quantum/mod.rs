@@ -0,0 +1,3 @@
+pub mod circuit;
+pub mod constraints;
+pub mod qasm;
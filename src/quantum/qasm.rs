@@ -0,0 +1,275 @@
+//! Reader for the OpenQASM 2 subset emitted by the offline synthesizer.
+//!
+//! Supported statements: the `OPENQASM 2.0;` header, `include`, `qreg`,
+//! `creg`, gate applications with optional parameters (`rz(pi/4) q[0];`),
+//! `measure q[i] -> c[j];`, `reset` and `barrier`. Operands must be indexed
+//! qubits, except that `barrier` also accepts whole registers. Custom `gate`
+//! definitions, `opaque` and classically conditioned `if` are rejected.
+//! `//` starts a comment.
+
+use thiserror::Error;
+
+use crate::quantum::circuit::{Circuit, Op};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("line {line}: {kind}")]
+pub struct QasmError {
+    pub line: usize,
+    pub kind: QasmErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum QasmErrorKind {
+    #[error("unsupported OpenQASM version {0}")]
+    UnsupportedVersion(String),
+
+    #[error("unsupported statement {0:?}")]
+    Unsupported(String),
+
+    #[error("malformed statement {0:?}")]
+    Malformed(String),
+
+    #[error("unknown register {0}")]
+    UnknownRegister(String),
+
+    #[error("register {0} declared twice")]
+    DuplicateRegister(String),
+
+    #[error("index {index} out of range for {register}[{size}]")]
+    IndexOutOfRange {
+        register: String,
+        index: u32,
+        size: u32,
+    },
+
+    #[error("qubit {0} used twice in one gate")]
+    RepeatedQubit(String),
+
+    #[error("qreg {0} takes the total qubit count past {max}", max = u32::MAX)]
+    TooManyQubits(String),
+
+    #[error("statement is missing its terminating ';'")]
+    MissingSemicolon,
+}
+
+impl QasmErrorKind {
+    fn at(self, line: usize) -> QasmError {
+        QasmError { line, kind: self }
+    }
+}
+
+#[derive(Default)]
+struct Registers {
+    qregs: Vec<(String, u32, u32)>, // name, offset, size
+    cregs: Vec<(String, u32)>,
+    width: u32, // total qubits; offset + size never overflows
+}
+
+impl Registers {
+    fn qreg(&self, name: &str) -> Option<(u32, u32)> {
+        self.qregs
+            .iter()
+            .find(|(n, _, _)| n == name)
+            .map(|&(_, offset, size)| (offset, size))
+    }
+
+    fn declared(&self, name: &str) -> bool {
+        self.qreg(name).is_some() || self.cregs.iter().any(|(n, _)| n == name)
+    }
+
+    fn add_qreg(&mut self, name: &str, size: u32) -> Option<()> {
+        let width = self.width.checked_add(size)?;
+        self.qregs.push((name.to_string(), self.width, size));
+        self.width = width;
+        Some(())
+    }
+}
+
+/// Split a leading `(params)` off `args` at the parenthesis that closes it,
+/// so `(-(pi/4)) q[0]` yields `-(pi/4)`. `None` when it is never closed.
+fn split_params(args: &str) -> Option<(&str, &str)> {
+    let mut depth = 0u32;
+    for (i, c) in args.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some((&args[1..i], &args[i + 1..]));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split `name[index]`.
+fn indexed(operand: &str) -> Option<(&str, Option<u32>)> {
+    match operand.split_once('[') {
+        None => Some((operand, None)),
+        Some((name, rest)) => {
+            let index = rest.strip_suffix(']')?.trim().parse().ok()?;
+            Some((name.trim(), Some(index)))
+        }
+    }
+}
+
+/// Parse a program into a [`Circuit`] over the concatenation of its quantum
+/// registers, in declaration order.
+pub fn parse_qasm(text: &str) -> Result<Circuit, QasmError> {
+    let mut regs = Registers::default();
+    let mut circuit = Circuit::new(0);
+
+    let mut statement = String::new();
+    let mut start_line = 1;
+    for (i, raw) in text.lines().enumerate() {
+        let code = raw.split("//").next().unwrap_or_default();
+        let mut rest = code;
+        while let Some(end) = rest.find(';') {
+            if statement.trim().is_empty() {
+                start_line = i + 1;
+            }
+            statement.push_str(&rest[..end]);
+            apply(statement.trim(), start_line, &mut regs, &mut circuit)?;
+            statement.clear();
+            rest = &rest[end + 1..];
+        }
+        if !rest.trim().is_empty() {
+            if statement.trim().is_empty() {
+                start_line = i + 1;
+            }
+            statement.push_str(rest);
+            statement.push(' ');
+        }
+    }
+    if !statement.trim().is_empty() {
+        return Err(QasmErrorKind::MissingSemicolon.at(start_line));
+    }
+    circuit.num_qubits = regs.width;
+    Ok(circuit)
+}
+
+fn apply(
+    statement: &str,
+    line: usize,
+    regs: &mut Registers,
+    circuit: &mut Circuit,
+) -> Result<(), QasmError> {
+    if statement.is_empty() {
+        return Ok(());
+    }
+    let malformed = || QasmErrorKind::Malformed(statement.to_string()).at(line);
+    let head_end = statement
+        .find(|c: char| c.is_whitespace() || c == '(')
+        .unwrap_or(statement.len());
+    let (head, args) = statement.split_at(head_end);
+
+    match head {
+        "OPENQASM" => {
+            let version = args.trim();
+            if version != "2.0" {
+                return Err(QasmErrorKind::UnsupportedVersion(version.to_string()).at(line));
+            }
+        }
+        "include" => {}
+        "qreg" | "creg" => {
+            let (name, size) = match indexed(args.trim()) {
+                Some((name, Some(size))) if !name.is_empty() => (name, size),
+                _ => return Err(malformed()),
+            };
+            if regs.declared(name) {
+                return Err(QasmErrorKind::DuplicateRegister(name.to_string()).at(line));
+            }
+            if head == "qreg" {
+                regs.add_qreg(name, size)
+                    .ok_or_else(|| QasmErrorKind::TooManyQubits(name.to_string()).at(line))?;
+            } else {
+                regs.cregs.push((name.to_string(), size));
+            }
+        }
+        "gate" | "opaque" | "if" => {
+            return Err(QasmErrorKind::Unsupported(head.to_string()).at(line));
+        }
+        "measure" => {
+            let (qubit, clbit) = args.split_once("->").ok_or_else(malformed)?;
+            let qubit = qubit_index(qubit.trim(), regs, line)?.ok_or_else(malformed)?;
+            let (creg, index) = indexed(clbit.trim()).ok_or_else(malformed)?;
+            let size = regs
+                .cregs
+                .iter()
+                .find(|(n, _)| n == creg)
+                .map(|&(_, size)| size)
+                .ok_or_else(|| QasmErrorKind::UnknownRegister(creg.to_string()).at(line))?;
+            match index {
+                Some(index) if index < size => {}
+                Some(index) => {
+                    return Err(QasmErrorKind::IndexOutOfRange {
+                        register: creg.to_string(),
+                        index,
+                        size,
+                    }
+                    .at(line))
+                }
+                None => return Err(malformed()),
+            }
+            circuit.ops.push(Op::Measure { qubit });
+        }
+        "reset" => {
+            let qubit = qubit_index(args.trim(), regs, line)?.ok_or_else(malformed)?;
+            circuit.ops.push(Op::Reset { qubit });
+        }
+        "barrier" => {
+            let mut qubits = Vec::new();
+            for operand in args.split(',') {
+                let operand = operand.trim();
+                match qubit_index(operand, regs, line)? {
+                    Some(q) => qubits.push(q),
+                    None => {
+                        let (offset, size) = regs.qreg(operand).ok_or_else(malformed)?;
+                        qubits.extend(offset..offset + size);
+                    }
+                }
+            }
+            circuit.ops.push(Op::Barrier { qubits });
+        }
+        name => {
+            let args = args.trim_start();
+            let operands = if args.starts_with('(') {
+                split_params(args).ok_or_else(malformed)?.1
+            } else {
+                args
+            };
+            let mut qubits = Vec::new();
+            for operand in operands.split(',') {
+                let operand = operand.trim();
+                let q = qubit_index(operand, regs, line)?.ok_or_else(malformed)?;
+                if qubits.contains(&q) {
+                    return Err(QasmErrorKind::RepeatedQubit(operand.to_string()).at(line));
+                }
+                qubits.push(q);
+            }
+            circuit.ops.push(Op::gate(name, qubits));
+        }
+    }
+    Ok(())
+}
+
+/// Flat index of `name[i]`; `None` for a bare register name.
+fn qubit_index(operand: &str, regs: &Registers, line: usize) -> Result<Option<u32>, QasmError> {
+    let (name, index) =
+        indexed(operand).ok_or_else(|| QasmErrorKind::Malformed(operand.to_string()).at(line))?;
+    let (offset, size) = regs
+        .qreg(name)
+        .ok_or_else(|| QasmErrorKind::UnknownRegister(name.to_string()).at(line))?;
+    match index {
+        None => Ok(None),
+        Some(index) if index < size => Ok(Some(offset + index)),
+        Some(index) => Err(QasmErrorKind::IndexOutOfRange {
+            register: name.to_string(),
+            index,
+            size,
+        }
+        .at(line)),
+    }
+}
@@ -1,3 +1,5 @@
+use crate::deeds::SiteId;
+use crate::tokens::ExtendedTokenState;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -12,9 +14,19 @@ pub struct TreeOfLifeSnapshot {
     pub blood: f64,
 }
 
+impl Default for TreeOfLifeSnapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TreeOfLifeSnapshot {
     pub fn new() -> Self {
-        let mut rng = rand::thread_rng();
+        Self::from_rng(&mut rand::thread_rng())
+    }
+
+    /// Draw an initial snapshot from a caller-supplied RNG (e.g. a seeded society RNG).
+    pub fn from_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
         let mut s = Self {
             lifeforce: rng.gen_range(0.5..1.0),
             decay: rng.gen_range(0.0..0.5),
@@ -27,6 +39,22 @@ impl TreeOfLifeSnapshot {
         s
     }
 
+    /// Six-scalar view of a WorldLine site's full token state.
+    ///
+    /// OXYGEN falls with POLLUTION, PAIN tracks EXPOSURE and BLOOD tracks LIFE.
+    pub fn from_tokens(tokens: &ExtendedTokenState) -> Self {
+        let mut s = Self {
+            lifeforce: tokens.lifeforce,
+            decay: tokens.decay,
+            fear: tokens.fear,
+            oxygen: 1.0 - tokens.pollution,
+            pain: tokens.exposure,
+            blood: tokens.life,
+        };
+        s.clamp();
+        s
+    }
+
     pub fn update_from_event(&mut self, is_good: bool) {
         if is_good {
             self.lifeforce = (self.lifeforce + 0.05).min(1.0);
@@ -59,6 +87,8 @@ impl TreeOfLifeSnapshot {
 #[derive(Clone, Debug)]
 pub struct MicroAgent {
     pub id: String,
+    /// WorldLine site the agent occupies, in site mode.
+    pub site: Option<SiteId>,
     pub tree_snapshot: TreeOfLifeSnapshot,
     pub calmstable: bool,
     pub overloaded: bool,
@@ -68,9 +98,14 @@ pub struct MicroAgent {
 
 impl MicroAgent {
     pub fn new(id: String) -> Self {
+        Self::with_snapshot(id, TreeOfLifeSnapshot::new())
+    }
+
+    pub fn with_snapshot(id: String, tree_snapshot: TreeOfLifeSnapshot) -> Self {
         Self {
             id,
-            tree_snapshot: TreeOfLifeSnapshot::new(),
+            site: None,
+            tree_snapshot,
             calmstable: false,
             overloaded: false,
             recovery: false,
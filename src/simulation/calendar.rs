@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Simulated calendar for MicroSociety: cycle `tick` happens at
+/// `epoch + tick * cycle_seconds` (Unix seconds), independent of wall-clock time.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SimCalendar {
+    pub epoch: u64,
+    pub cycle_seconds: u64,
+}
+
+impl SimCalendar {
+    pub fn new(epoch: u64, cycle_seconds: u64) -> Self {
+        Self {
+            epoch,
+            cycle_seconds,
+        }
+    }
+
+    /// Seeded societies start on this calendar: one simulated hour per cycle
+    /// from the Unix epoch, so their timestamps never depend on the wall clock.
+    pub fn deterministic() -> Self {
+        Self::new(0, 3_600)
+    }
+
+    /// Simulated timestamp of cycle `tick`.
+    pub fn timestamp_at(&self, tick: u64) -> u64 {
+        self.epoch
+            .saturating_add(tick.saturating_mul(self.cycle_seconds))
+    }
+}
+
+/// A calendar that would stamp the next cycle before the ledger's last event,
+/// so the next append would break timestamp monotonicity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error(
+    "calendar stamps tick {tick} at {timestamp}, before the ledger's last event at {last_timestamp}"
+)]
+pub struct CalendarBehindLedger {
+    pub tick: u64,
+    pub timestamp: u64,
+    pub last_timestamp: u64,
+}
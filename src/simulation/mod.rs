@@ -1,5 +1,12 @@
 pub mod agent;
+pub mod calendar;
+pub mod policy;
 pub mod society;
 
 pub use agent::{MicroAgent, TreeOfLifeSnapshot};
+pub use calendar::{CalendarBehindLedger, SimCalendar};
+pub use policy::{
+    AgentPolicy, AggressorPolicy, AltruisticPolicy, RandomPolicy, ScriptedPolicy, SiteView,
+    TitForTatPolicy,
+};
 pub use society::MicroSociety;
@@ -0,0 +1,210 @@
+use crate::deeds::{AgentId, DeedRequest, DeedType, SiteId, Tick};
+use crate::tokens::ExtendedTokenState;
+use rand::{Rng, RngCore};
+use std::collections::VecDeque;
+
+/// Read-only view handed to an agent's policy: its own site and its neighbors.
+#[derive(Debug, Clone)]
+pub struct SiteView<'a> {
+    pub tick: Tick,
+    pub agent: AgentId,
+    pub site: SiteId,
+    pub state: &'a ExtendedTokenState,
+    /// Neighboring sites in ascending id order; never contains `site` itself.
+    pub neighbors: Vec<(SiteId, &'a ExtendedTokenState)>,
+    deed_id: u64,
+}
+
+impl<'a> SiteView<'a> {
+    pub(crate) fn new(
+        tick: Tick,
+        agent: AgentId,
+        site: SiteId,
+        state: &'a ExtendedTokenState,
+        neighbors: Vec<(SiteId, &'a ExtendedTokenState)>,
+        deed_id: u64,
+    ) -> Self {
+        Self {
+            tick,
+            agent,
+            site,
+            state,
+            neighbors,
+            deed_id,
+        }
+    }
+
+    /// Request from this agent's site, stamped with the current tick and deed id.
+    pub fn request(
+        &self,
+        deed_type: DeedType,
+        target_site: Option<SiteId>,
+        intensity: f64,
+    ) -> DeedRequest {
+        DeedRequest {
+            tick: self.tick,
+            deed_id: self.deed_id,
+            proposer: self.agent,
+            deed_type,
+            source_site: self.site,
+            target_site,
+            intensity,
+        }
+    }
+
+    /// Neighbor minimizing `key`, ties broken by lowest site id.
+    fn neighbor_min_by(
+        &self,
+        key: impl Fn(&ExtendedTokenState) -> f64,
+    ) -> Option<(SiteId, &'a ExtendedTokenState)> {
+        self.neighbors
+            .iter()
+            .min_by(|a, b| key(a.1).total_cmp(&key(b.1)))
+            .copied()
+    }
+}
+
+/// How an agent decides which deed to propose each cycle.
+///
+/// Policies only see a `SiteView` and only produce a request; the
+/// ValidationKernel still decides what actually happens.
+pub trait AgentPolicy {
+    fn propose(&mut self, view: &SiteView<'_>, rng: &mut dyn RngCore) -> DeedRequest;
+}
+
+const DEED_TYPES: [DeedType; 6] = [
+    DeedType::Help,
+    DeedType::Conflict,
+    DeedType::Colonize,
+    DeedType::Repair,
+    DeedType::EmitPollution,
+    DeedType::DeployTech,
+];
+
+/// Uniformly random deed type, neighbor and intensity.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomPolicy;
+
+impl AgentPolicy for RandomPolicy {
+    fn propose(&mut self, view: &SiteView<'_>, rng: &mut dyn RngCore) -> DeedRequest {
+        let deed_type = DEED_TYPES[rng.gen_range(0..DEED_TYPES.len())];
+        let target = match deed_type {
+            DeedType::Help | DeedType::Conflict | DeedType::Colonize | DeedType::EmitPollution
+                if !view.neighbors.is_empty() =>
+            {
+                Some(view.neighbors[rng.gen_range(0..view.neighbors.len())].0)
+            }
+            _ => None,
+        };
+        view.request(deed_type, target, rng.gen_range(0.0..1.0))
+    }
+}
+
+/// Attacks: full-intensity Conflict against the neighbor with the most
+/// POWER, or DeployTech when alone. It never weighs what a deed gains it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AggressorPolicy;
+
+impl AgentPolicy for AggressorPolicy {
+    fn propose(&mut self, view: &SiteView<'_>, _rng: &mut dyn RngCore) -> DeedRequest {
+        match view.neighbor_min_by(|s| -s.power) {
+            Some((target, _)) => view.request(DeedType::Conflict, Some(target), 1.0),
+            None => view.request(DeedType::DeployTech, None, 1.0),
+        }
+    }
+}
+
+/// Repairs its own site once POLLUTION or DECAY passes `repair_threshold`,
+/// otherwise Helps the neighbor with the lowest LIFEFORCE.
+#[derive(Debug, Clone, Copy)]
+pub struct AltruisticPolicy {
+    pub repair_threshold: f64,
+    pub intensity: f64,
+}
+
+impl Default for AltruisticPolicy {
+    fn default() -> Self {
+        Self {
+            repair_threshold: 0.1,
+            intensity: 0.5,
+        }
+    }
+}
+
+impl AgentPolicy for AltruisticPolicy {
+    fn propose(&mut self, view: &SiteView<'_>, _rng: &mut dyn RngCore) -> DeedRequest {
+        let needs_repair = view.state.pollution > self.repair_threshold
+            || view.state.decay > self.repair_threshold;
+        match view.neighbor_min_by(|s| s.lifeforce) {
+            Some((target, _)) if !needs_repair => {
+                view.request(DeedType::Help, Some(target), self.intensity)
+            }
+            _ => view.request(DeedType::Repair, None, self.intensity),
+        }
+    }
+}
+
+/// Tit-for-tat on TRUST: Help neighbors whose TRUST is at least
+/// `trust_threshold`, retaliate with Conflict against the least trusted one.
+///
+/// Starting from neutral TRUST it cooperates first; Help raises TRUST on both
+/// sides and Conflict lowers it, so neighbors' TRUST carries the history.
+#[derive(Debug, Clone, Copy)]
+pub struct TitForTatPolicy {
+    pub trust_threshold: f64,
+    pub intensity: f64,
+}
+
+impl Default for TitForTatPolicy {
+    fn default() -> Self {
+        Self {
+            trust_threshold: 0.0,
+            intensity: 0.5,
+        }
+    }
+}
+
+impl AgentPolicy for TitForTatPolicy {
+    fn propose(&mut self, view: &SiteView<'_>, _rng: &mut dyn RngCore) -> DeedRequest {
+        let Some((target, state)) = view.neighbor_min_by(|s| s.trust) else {
+            return view.request(DeedType::Repair, None, self.intensity);
+        };
+        if state.trust >= self.trust_threshold {
+            view.request(DeedType::Help, Some(target), self.intensity)
+        } else {
+            view.request(DeedType::Conflict, Some(target), self.intensity)
+        }
+    }
+}
+
+/// Replays a fixed sequence of requests, one per cycle, then idles with a
+/// zero-intensity Repair.
+///
+/// Each replayed request keeps its deed type, target and intensity but is
+/// re-stamped with the current tick, deed id, proposer and source site.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedPolicy {
+    script: VecDeque<DeedRequest>,
+}
+
+impl ScriptedPolicy {
+    pub fn new(script: impl IntoIterator<Item = DeedRequest>) -> Self {
+        Self {
+            script: script.into_iter().collect(),
+        }
+    }
+
+    /// Scripted requests not yet replayed.
+    pub fn remaining(&self) -> usize {
+        self.script.len()
+    }
+}
+
+impl AgentPolicy for ScriptedPolicy {
+    fn propose(&mut self, view: &SiteView<'_>, _rng: &mut dyn RngCore) -> DeedRequest {
+        match self.script.pop_front() {
+            Some(step) => view.request(step.deed_type, step.target_site, step.intensity),
+            None => view.request(DeedType::Repair, None, 0.0),
+        }
+    }
+}
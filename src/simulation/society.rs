@@ -1,40 +1,263 @@
-use crate::ledger::{DeedEvent, Ledger};
-use crate::simulation::MicroAgent;
-use crate::utils::crypto::compute_sha256_hash;
-use crate::utils::rand_events::generate_ecological_event;
-use crate::utils::time::current_timestamp;
+use crate::deeds::{
+    DeedRequest, DeedStatus, DeedType, InvariantParams, SiteId, ValidatedDeed, ValidationKernel,
+    WorldLine,
+};
+use crate::doctrine::Doctrine;
+use crate::ledger::{DeedEvent, Ledger, LedgerError};
+use crate::simulation::{
+    AgentPolicy, CalendarBehindLedger, MicroAgent, RandomPolicy, SimCalendar, SiteView,
+    TreeOfLifeSnapshot,
+};
+use crate::topology::Topology;
+use crate::transport::{transport_step, TransportParams};
+use crate::utils::rand_events::generate_ecological_event_with;
+use crate::utils::time::{Clock, SystemClock};
+use crate::world_invariants::GlobalInvariantParams;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
 use serde_json::json;
-use uuid::Uuid;
+use sha2::{Digest, Sha256};
+use uuid::Builder;
 
 /// Non-actuating 1D lattice of MicroAgents plus an internal observer Ledger.[file:1]
 pub struct MicroSociety {
     pub agents: Vec<MicroAgent>,
     pub ledger: Ledger,
+    rng: Box<dyn RngCore>,
+    time: TimeSource,
+    tick: u64,
+    topology: Topology,
+    sites: Option<SiteLayer>,
+}
+
+/// Site mode state: the shared WorldLine, the kernel every deed goes through,
+/// and one policy per agent.
+struct SiteLayer {
+    world: WorldLine,
+    kernel: ValidationKernel,
+    policies: Vec<Box<dyn AgentPolicy>>,
+    transport: Option<TransportParams>,
+    next_deed_id: u64,
+}
+
+/// Where DeedEvent timestamps come from.
+enum TimeSource {
+    Clock(Box<dyn Clock>),
+    Calendar(SimCalendar),
 }
 
 impl MicroSociety {
+    /// Society seeded from OS entropy and stamped by the wall clock; runs are
+    /// not reproducible.
     pub fn new(agent_count: usize) -> Self {
+        let mut society = Self::with_rng(agent_count, Box::new(StdRng::from_entropy()));
+        society.set_clock(Box::new(SystemClock));
+        society
+    }
+
+    /// Society whose every random draw (initial snapshots and events) comes
+    /// from one RNG seeded with `seed`. Timestamps follow
+    /// `SimCalendar::deterministic`, so equal seeds give identical ledgers.
+    pub fn with_seed(agent_count: usize, seed: u64) -> Self {
+        Self::with_rng(agent_count, Box::new(StdRng::seed_from_u64(seed)))
+    }
+
+    /// Society driven by a caller-supplied RNG source, stamped from
+    /// `SimCalendar::deterministic` until `set_clock` or `set_calendar`.
+    pub fn with_rng(agent_count: usize, mut rng: Box<dyn RngCore>) -> Self {
         let mut agents = Vec::with_capacity(agent_count);
         for i in 0..agent_count {
-            agents.push(MicroAgent::new(format!("agent_{}", i)));
+            let snapshot = TreeOfLifeSnapshot::from_rng(&mut rng);
+            agents.push(MicroAgent::with_snapshot(format!("agent_{}", i), snapshot));
+        }
+        Self {
+            agents,
+            ledger: Ledger::new(),
+            rng,
+            time: TimeSource::Calendar(SimCalendar::deterministic()),
+            tick: 0,
+            topology: Topology::ring(),
+            sites: None,
         }
+    }
+
+    /// Site mode: agent `i` occupies site `i` of `world`, and every cycle each
+    /// agent's policy proposes a `DeedRequest` that `ValidationKernel` validates
+    /// and applies before the outcome is mirrored into the ledger.
+    ///
+    /// Every agent starts with a `RandomPolicy`; see `set_policy`. Timestamps
+    /// follow `SimCalendar::deterministic` until `set_clock` or `set_calendar`.
+    pub fn with_world_line(
+        world: WorldLine,
+        invariants: InvariantParams,
+        rng: Box<dyn RngCore>,
+    ) -> Self {
+        let agents = world
+            .sites
+            .iter()
+            .enumerate()
+            .map(|(i, tokens)| {
+                let mut agent = MicroAgent::with_snapshot(
+                    format!("agent_{}", i),
+                    TreeOfLifeSnapshot::from_tokens(tokens),
+                );
+                agent.site = Some(i as SiteId);
+                agent.update_predicates();
+                agent
+            })
+            .collect();
+        let policies = (0..world.len())
+            .map(|_| Box::new(RandomPolicy) as Box<dyn AgentPolicy>)
+            .collect();
         Self {
             agents,
             ledger: Ledger::new(),
+            rng,
+            time: TimeSource::Calendar(SimCalendar::deterministic()),
+            tick: 0,
+            topology: Topology::ring(),
+            sites: Some(SiteLayer {
+                world,
+                kernel: ValidationKernel::with_topology(invariants, Topology::ring()),
+                policies,
+                transport: None,
+                next_deed_id: 0,
+            }),
+        }
+    }
+
+    /// Rearrange agents; the default is a radius-1 ring.
+    ///
+    /// In site mode this also sets the kernel's range checks and spillover.
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+        if let Some(layer) = self.sites.as_mut() {
+            layer.kernel.topology = Some(topology);
+        }
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Block site-mode deeds that break or worsen a world-level invariant.
+    /// Has no effect outside site mode.
+    pub fn set_global_invariants(&mut self, global: GlobalInvariantParams) {
+        if let Some(layer) = self.sites.as_mut() {
+            layer.kernel.global = Some(global);
+        }
+    }
+
+    /// Run the environmental transport step over the topology at the end of
+    /// every site-mode cycle. Has no effect outside site mode.
+    pub fn set_transport(&mut self, params: TransportParams) {
+        if let Some(layer) = self.sites.as_mut() {
+            layer.transport = Some(params);
+        }
+    }
+
+    /// Take invariants, global limits and transport from `doctrine`, keeping
+    /// the current topology; mirrored deeds then carry the doctrine's hash.
+    /// Has no effect outside site mode.
+    pub fn set_doctrine(&mut self, doctrine: &Doctrine) {
+        let topology = self.topology;
+        if let Some(layer) = self.sites.as_mut() {
+            layer.kernel = ValidationKernel::from_doctrine(doctrine);
+            layer.kernel.topology = Some(topology);
+            layer.transport = doctrine.transport;
+        }
+    }
+
+    /// Give agent `agent` its own deed policy, so one society can mix policies.
+    ///
+    /// Returns `false` (and changes nothing) outside site mode or for an unknown agent.
+    pub fn set_policy(&mut self, agent: usize, policy: Box<dyn AgentPolicy>) -> bool {
+        match self
+            .sites
+            .as_mut()
+            .and_then(|layer| layer.policies.get_mut(agent))
+        {
+            Some(slot) => {
+                *slot = policy;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The WorldLine agents act on, in site mode.
+    pub fn world(&self) -> Option<&WorldLine> {
+        self.sites.as_ref().map(|layer| &layer.world)
+    }
+
+    /// Replace the wall clock used to stamp DeedEvents (e.g. with a `SimulatedClock`).
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.time = TimeSource::Clock(clock);
+    }
+
+    /// Stamp DeedEvents from a simulated calendar instead of a clock, so each
+    /// cycle advances `calendar.cycle_seconds` of simulated history.
+    ///
+    /// Refused, leaving the society unchanged, when the calendar would stamp
+    /// the next cycle before the ledger's last event.
+    pub fn set_calendar(&mut self, calendar: SimCalendar) -> Result<(), CalendarBehindLedger> {
+        check_calendar(&calendar, self.tick, &self.ledger)?;
+        self.time = TimeSource::Calendar(calendar);
+        Ok(())
+    }
+
+    /// Continue an existing ledger: new events link onto its head, and cycle
+    /// ticks resume after the last recorded tick.
+    ///
+    /// Refused, leaving the society unchanged, when the society's calendar
+    /// would stamp the next cycle before the ledger's last event.
+    pub fn adopt_ledger(&mut self, ledger: Ledger) -> Result<(), CalendarBehindLedger> {
+        let tick = ledger
+            .all_events()
+            .iter()
+            .rev()
+            .find_map(|e| e.tick)
+            .map_or(0, |t| t + 1);
+        if let TimeSource::Calendar(calendar) = &self.time {
+            check_calendar(calendar, tick, &ledger)?;
+        }
+        self.tick = tick;
+        self.ledger = ledger;
+        Ok(())
+    }
+
+    /// Number of completed cycles; also the tick of the next cycle.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Current time as seen by this society: the calendar time of the next
+    /// cycle, or the clock's now.
+    pub fn now(&self) -> u64 {
+        match &self.time {
+            TimeSource::Clock(clock) => clock.now(),
+            TimeSource::Calendar(calendar) => calendar.timestamp_at(self.tick),
         }
     }
 
     /// One bounded simulation step: local random events, Tree updates, and DeedEvent logging.[file:1]
-    pub fn simulate_cycle(&mut self) {
+    ///
+    /// In site mode the events are validated deeds on the WorldLine instead.
+    /// Stops at the first event the ledger refuses and returns that error.
+    pub fn simulate_cycle(&mut self) -> Result<(), LedgerError> {
+        if self.sites.is_some() {
+            return self.simulate_site_cycle();
+        }
+
         let agent_len = self.agents.len();
         if agent_len == 0 {
-            return;
+            return Ok(());
         }
 
-        let prev_hash = self.ledger.last_hash().to_string();
-
+        let tick = self.tick;
         for i in 0..agent_len {
-            let event_type = generate_ecological_event();
+            let prev_hash = self.ledger.last_hash().to_string();
+            let event_type = generate_ecological_event_with(&mut self.rng);
             let is_good = matches!(
                 event_type.as_str(),
                 "ecological_sharing" | "resource_aid" | "math_science_education"
@@ -44,15 +267,24 @@ impl MicroSociety {
             self.agents[i].update_predicates();
 
             let actor_id = self.agents[i].id.clone();
-            let target_id = format!("agent_{}", (i + 1) % agent_len);
+            // Next neighbor after `i`, wrapping to the lowest one.
+            let neighbors = self.topology.neighbors(i as u64, agent_len);
+            let target_ids = neighbors
+                .iter()
+                .find(|(j, _)| *j > i as u64)
+                .or(neighbors.first())
+                .map(|(j, _)| format!("agent_{}", j))
+                .into_iter()
+                .collect();
 
             let mut deed = DeedEvent {
-                event_id: Uuid::new_v4().to_string(),
-                timestamp: current_timestamp(),
-                prev_hash: prev_hash.clone(),
+                event_id: event_id(&prev_hash, tick, &actor_id),
+                timestamp: self.now(),
+                tick: Some(tick),
+                prev_hash,
                 self_hash: String::new(),
                 actor_id,
-                target_ids: vec![target_id],
+                target_ids,
                 deed_type: event_type.clone(),
                 tags: vec!["microlife".to_string()],
                 context_json: json!({
@@ -68,13 +300,152 @@ impl MicroSociety {
                     Vec::new()
                 },
                 life_harm_flag: false,
+                signature: None,
+            };
+
+            deed.self_hash = deed.compute_hash();
+
+            self.ledger.append(deed)?;
+        }
+        self.tick += 1;
+        Ok(())
+    }
+
+    fn simulate_site_cycle(&mut self) -> Result<(), LedgerError> {
+        let tick = self.tick;
+        for i in 0..self.agents.len() {
+            let req = self.propose_deed(i, tick);
+            let layer = self.sites.as_mut().expect("site mode");
+            let Some(validated) = layer.kernel.process_deed(&mut layer.world, req) else {
+                continue;
             };
 
-            let serialized = serde_json::to_string(&deed).expect("serialize deed");
-            let hash = compute_sha256_hash(serialized.as_bytes());
-            deed.self_hash = hash;
+            let touched = std::iter::once(validated.source_site)
+                .chain(validated.target_site)
+                .chain(validated.spillover.iter().map(|s| s.site));
+            for site in touched {
+                observe_site(&mut self.agents[site as usize], &layer.world);
+            }
+            self.mirror_validated(&validated)?;
+        }
 
-            self.ledger.append(deed);
+        let layer = self.sites.as_mut().expect("site mode");
+        if let Some(params) = &layer.transport {
+            transport_step(&mut layer.world, &self.topology, params);
+            for agent in &mut self.agents {
+                observe_site(agent, &layer.world);
+            }
         }
+        self.tick += 1;
+        Ok(())
+    }
+
+    /// Ask agent `i`'s policy for a deed, showing it its site and its
+    /// topology neighbors.
+    fn propose_deed(&mut self, i: usize, tick: u64) -> DeedRequest {
+        let layer = self.sites.as_mut().expect("site mode");
+        let view = SiteView::new(
+            tick,
+            i as u64,
+            i as SiteId,
+            &layer.world.sites[i],
+            self.topology
+                .neighbors(i as SiteId, layer.world.len())
+                .into_iter()
+                .map(|(j, _)| (j, &layer.world.sites[j as usize]))
+                .collect(),
+            layer.next_deed_id,
+        );
+        layer.next_deed_id += 1;
+        layer.policies[i].propose(&view, &mut self.rng)
+    }
+
+    /// Append a DeedEvent recording the kernel's verdict on one deed.
+    fn mirror_validated(&mut self, validated: &ValidatedDeed) -> Result<(), LedgerError> {
+        let actor = &self.agents[validated.source_site as usize];
+        let mut ethics_flags = Vec::new();
+        match validated.status {
+            DeedStatus::Blocked => ethics_flags.push("blocked".to_string()),
+            DeedStatus::Transformed => ethics_flags.push("transformed".to_string()),
+            DeedStatus::Success => {}
+        }
+        if validated.status != DeedStatus::Blocked
+            && matches!(
+                validated.deed_type,
+                DeedType::Conflict | DeedType::EmitPollution
+            )
+        {
+            ethics_flags.push("minor_harm".to_string());
+        }
+
+        let prev_hash = self.ledger.last_hash().to_string();
+        let mut deed = DeedEvent {
+            event_id: event_id(&prev_hash, validated.tick, &actor.id),
+            timestamp: self.now(),
+            tick: Some(validated.tick),
+            prev_hash,
+            self_hash: String::new(),
+            actor_id: actor.id.clone(),
+            target_ids: validated
+                .target_site
+                .map(|site| self.agents[site as usize].id.clone())
+                .into_iter()
+                .collect(),
+            deed_type: format!("{:?}", validated.deed_type),
+            tags: vec!["microlife".to_string(), "jetson_line".to_string()],
+            context_json: json!({
+                "validated_deed": validated,
+                "tree_snapshot": actor.tree_snapshot,
+                "calmstable": actor.calmstable,
+                "overloaded": actor.overloaded,
+                "recovery": actor.recovery,
+                "unfairdrain": actor.unfairdrain
+            }),
+            ethics_flags,
+            life_harm_flag: false,
+            signature: None,
+        };
+        deed.self_hash = deed.compute_hash();
+        self.ledger.append(deed)
+    }
+}
+
+/// Refresh a site-mode agent's snapshot and predicates from its site's tokens.
+fn observe_site(agent: &mut MicroAgent, world: &WorldLine) {
+    if let Some(tokens) = agent.site.and_then(|site| world.get(site)) {
+        agent.tree_snapshot = TreeOfLifeSnapshot::from_tokens(tokens);
+        agent.update_predicates();
+    }
+}
+
+/// Event id for the event `actor` appends at `tick` onto the head `prev_hash`.
+///
+/// Every head is the hash of a distinct event, so ids never repeat within a
+/// chain, including across runs that resume it through `adopt_ledger`.
+fn event_id(prev_hash: &str, tick: u64, actor: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(prev_hash.as_bytes());
+    hasher.update(tick.to_be_bytes());
+    hasher.update(actor.as_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    Builder::from_custom_bytes(bytes).into_uuid().to_string()
+}
+
+/// `calendar` may stamp cycle `tick` onto `ledger` only at or after its last event.
+fn check_calendar(
+    calendar: &SimCalendar,
+    tick: u64,
+    ledger: &Ledger,
+) -> Result<(), CalendarBehindLedger> {
+    let timestamp = calendar.timestamp_at(tick);
+    match ledger.all_events().last() {
+        Some(last) if timestamp < last.timestamp => Err(CalendarBehindLedger {
+            tick,
+            timestamp,
+            last_timestamp: last.timestamp,
+        }),
+        _ => Ok(()),
     }
 }
@@ -1,5 +1,5 @@
 use crate::societal::model::{
-    GovernanceEnvelope, SocialImpactVector, SocietalState, TechDomain, TechScenario,
+    SocialImpactVector, SocietalState, TechDomain, TechScenario,
 };
 
 fn domain_risk_weight(domain: TechDomain) -> f32 {
@@ -21,23 +21,23 @@ fn realized_impact(scenario: &TechScenario) -> SocialImpactVector {
     // Governance pulls us toward the target; centralization and speed pull away.
     // Weight governance more as adoption increases (early: narratives dominate,
     // later: structures dominate).
-    let w_gov = (scenario.adoption_rate).clamp(0.0, 1.0);
+    let w_gov = scenario.adoption_rate.clamp(0.0, 1.0);
     let mut impact = scenario.target_impact;
 
     // Pull down non‑exclusion and antistigma when centralization is high.
-    impact.nonexclusion *= (1.0 - 0.6 * centralization_penalty);
-    impact.antistigma *= (1.0 - 0.3 * centralization_penalty);
+    impact.nonexclusion *= 1.0 - 0.6 * centralization_penalty;
+    impact.antistigma *= 1.0 - 0.3 * centralization_penalty;
 
     // Very fast rollout with mediocre governance degrades peacekeeping.
     let rollout_stress = speed_penalty * (1.0 - q);
-    impact.peacekeeping *= (1.0 - 0.7 * rollout_stress);
+    impact.peacekeeping *= 1.0 - 0.7 * rollout_stress;
 
     // Eco impact: domain aware; power and nano can stress ecology if misgoverned.
     let eco_stress_factor = match scenario.domain {
         TechDomain::Power | TechDomain::Nano => 0.8,
         _ => 0.4,
     } * (1.0 - q);
-    impact.eco *= (1.0 - eco_stress_factor);
+    impact.eco *= 1.0 - eco_stress_factor;
 
     // Blend with a neutral “baseline society” impact to avoid extremes.
     let baseline = SocialImpactVector {
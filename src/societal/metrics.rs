@@ -1,4 +1,4 @@
-use crate::societal::model::{GovernanceEnvelope, SocietalState};
+use crate::societal::model::SocietalState;
 use crate::societal::word_math::WordMathScores;
 
 #[derive(Clone, Copy, Debug)]
@@ -0,0 +1,5 @@
+pub mod api;
+pub mod engine;
+pub mod metrics;
+pub mod model;
+pub mod word_math;
@@ -34,6 +34,7 @@ pub struct SpectralAlert {
 
 /// Configuration for thresholds used by spectral diagnostics.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct SpectralParams {
     // BEAST/PLAGUE-like thresholds
     pub death_high: f64,
@@ -51,6 +52,9 @@ pub struct SpectralParams {
     // TwistOfFate accumulation thresholds
     pub twistof_fate_window: u64,
     pub twistof_fate_severity_threshold: f64,
+
+    // WeatherCreation reference ceiling for BIOLOAD
+    pub bioload_max_site: f64,
 }
 
 /// Immutable snapshot of spectral monitoring state.
@@ -199,6 +199,7 @@ impl ExtendedTokenState {
     /// - reduces BIOLOAD and POLLUTION,
     /// - costs CHURCH and possibly POWER,
     /// - can slightly increase JUSTICE and TRUST.
+    #[allow(clippy::too_many_arguments)]
     pub fn apply_repair_deed(
         &mut self,
         bioload_reduction: f64,
@@ -244,6 +245,7 @@ impl ExtendedTokenState {
     /// - CHURCH and LIFE / LIFEFORCE benefit for the recipient,
     /// - TRUST increase for both,
     /// - modest BIOLOAD increase (sacrifice).
+    #[allow(clippy::too_many_arguments)]
     pub fn apply_help_deed_with(
         &mut self,
         other: &mut ExtendedTokenState,
@@ -292,6 +294,7 @@ impl ExtendedTokenState {
     /// - TRUST loss for both,
     /// - BIOLOAD, POLLUTION, DECAY increase for both,
     /// - JUSTICE decrease.
+    #[allow(clippy::too_many_arguments)]
     pub fn apply_conflict_with(
         &mut self,
         other: &mut ExtendedTokenState,
@@ -0,0 +1,130 @@
+// src/topology.rs
+// Neighborhood structure over Jetson-Line sites.
+//
+// A WorldLine stores sites in a flat Vec indexed by SiteId; the topology is
+// what says which of those sites are adjacent and how far apart they are.
+// It is pure geometry: no token state, no randomness.
+
+use serde::{Deserialize, Serialize};
+
+use crate::deeds::SiteId;
+
+/// How sites are arranged and how far an agent's reach extends.
+///
+/// Two distinct sites are neighbors when their distance is at most `radius`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Topology {
+    /// Open 1-D line; the end sites have fewer neighbors.
+    Line { radius: u64 },
+    /// 1-D ring; the last site is adjacent to the first.
+    Ring { radius: u64 },
+    /// Row-major 2-D lattice `width` sites wide, with Manhattan distance.
+    /// The last row may be partial.
+    Grid { width: u64, radius: u64 },
+}
+
+impl Topology {
+    pub fn line() -> Self {
+        Topology::Line { radius: 1 }
+    }
+
+    pub fn ring() -> Self {
+        Topology::Ring { radius: 1 }
+    }
+
+    pub fn grid(width: u64) -> Self {
+        Topology::Grid { width, radius: 1 }
+    }
+
+    /// Same arrangement with a radius-`radius` neighborhood.
+    pub fn with_radius(self, radius: u64) -> Self {
+        match self {
+            Topology::Line { .. } => Topology::Line { radius },
+            Topology::Ring { .. } => Topology::Ring { radius },
+            Topology::Grid { width, .. } => Topology::Grid { width, radius },
+        }
+    }
+
+    pub fn radius(&self) -> u64 {
+        match *self {
+            Topology::Line { radius } | Topology::Ring { radius } => radius,
+            Topology::Grid { radius, .. } => radius,
+        }
+    }
+
+    /// Distance between two sites of a `num_sites`-site world, or `None` if
+    /// either site does not exist.
+    pub fn distance(&self, a: SiteId, b: SiteId, num_sites: usize) -> Option<u64> {
+        let n = num_sites as u64;
+        if a >= n || b >= n {
+            return None;
+        }
+        Some(match *self {
+            Topology::Line { .. } => a.abs_diff(b),
+            Topology::Ring { .. } => {
+                let d = a.abs_diff(b);
+                d.min(n - d)
+            }
+            Topology::Grid { width, .. } => {
+                let width = width.max(1);
+                (a % width).abs_diff(b % width) + (a / width).abs_diff(b / width)
+            }
+        })
+    }
+
+    /// True when `target` is a distinct site within reach of `source`.
+    pub fn in_range(&self, source: SiteId, target: SiteId, num_sites: usize) -> bool {
+        source != target
+            && self
+                .distance(source, target, num_sites)
+                .is_some_and(|d| d <= self.radius())
+    }
+
+    /// Neighbors of `site` with their distances, in ascending site order.
+    ///
+    /// Only the offsets within the radius are visited, so the cost depends on
+    /// the radius rather than on the size of the world.
+    pub fn neighbors(&self, site: SiteId, num_sites: usize) -> Vec<(SiteId, u64)> {
+        let n = num_sites as u64;
+        if site >= n {
+            return Vec::new();
+        }
+        let mut found = Vec::new();
+        match *self {
+            Topology::Line { radius } => {
+                for d in 1..=radius.min(n) {
+                    if let Some(left) = site.checked_sub(d) {
+                        found.push((left, d));
+                    }
+                    if site + d < n {
+                        found.push((site + d, d));
+                    }
+                }
+            }
+            Topology::Ring { radius } => {
+                for d in 1..=radius.min(n / 2) {
+                    found.push(((site + d) % n, d));
+                    found.push(((site + n - d) % n, d));
+                }
+            }
+            Topology::Grid { width, radius } => {
+                let width = width.max(1);
+                let rows = n.div_ceil(width);
+                let (col, row) = (site % width, site / width);
+                let radius = radius.min(width + rows);
+                for r in row.saturating_sub(radius)..(row + radius + 1).min(rows) {
+                    let reach = radius - r.abs_diff(row);
+                    for c in col.saturating_sub(reach)..(col + reach + 1).min(width) {
+                        let other = r * width + c;
+                        if other != site && other < n {
+                            found.push((other, c.abs_diff(col) + r.abs_diff(row)));
+                        }
+                    }
+                }
+            }
+        }
+        found.sort_unstable();
+        found.dedup_by_key(|&mut (other, _)| other);
+        found
+    }
+}
@@ -0,0 +1,79 @@
+// src/transport.rs
+// Per-tick environmental transport between neighboring Jetson-Line sites.
+//
+// One `transport_step` call:
+// - diffuses POLLUTION over the topology (total POLLUTION is conserved),
+// - spreads FEAR socially the same way (total FEAR is conserved),
+// - rewrites EXPOSURE from local and neighboring POLLUTION.
+//
+// Diffusion is a symmetric exchange along topology edges, scaled so every
+// new value is a convex combination of old ones: nothing goes negative and
+// nothing leaves the range the line already spans.
+
+use serde::{Deserialize, Serialize};
+
+use crate::deeds::WorldLine;
+use crate::topology::Topology;
+
+/// Coefficients for the transport step; rates are fractions in [0, 1].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransportParams {
+    /// Share of each POLLUTION gradient evened out per tick.
+    pub pollution_diffusion: f64,
+    /// Share of each FEAR gradient evened out per tick.
+    pub fear_diffusion: f64,
+    /// EXPOSURE weight on the site's own POLLUTION.
+    pub exposure_local: f64,
+    /// EXPOSURE weight on the mean POLLUTION of its neighbors.
+    pub exposure_neighbor: f64,
+}
+
+/// Apply one transport step to every site of `world`.
+pub fn transport_step(world: &mut WorldLine, topology: &Topology, params: &TransportParams) {
+    let n = world.len();
+    let neighbors: Vec<Vec<usize>> = (0..n as u64)
+        .map(|site| {
+            topology
+                .neighbors(site, n)
+                .into_iter()
+                .map(|(id, _)| id as usize)
+                .collect()
+        })
+        .collect();
+    // Normalizing by the largest degree keeps each update a convex combination.
+    let max_degree = neighbors.iter().map(Vec::len).max().unwrap_or(0).max(1) as f64;
+
+    let diffuse = |values: Vec<f64>, rate: f64| -> Vec<f64> {
+        let rate = rate.clamp(0.0, 1.0) / max_degree;
+        values
+            .iter()
+            .zip(&neighbors)
+            .map(|(v, adj)| v + rate * adj.iter().map(|&j| values[j] - v).sum::<f64>())
+            .collect()
+    };
+
+    let pollution = diffuse(
+        world.sites.iter().map(|s| s.pollution).collect(),
+        params.pollution_diffusion,
+    );
+    let fear = diffuse(
+        world.sites.iter().map(|s| s.fear).collect(),
+        params.fear_diffusion,
+    );
+
+    for (i, site) in world.sites.iter_mut().enumerate() {
+        let adj = &neighbors[i];
+        let neighbor_mean = if adj.is_empty() {
+            0.0
+        } else {
+            adj.iter().map(|&j| pollution[j]).sum::<f64>() / adj.len() as f64
+        };
+
+        site.pollution = pollution[i];
+        site.fear = fear[i];
+        site.exposure =
+            params.exposure_local * pollution[i] + params.exposure_neighbor * neighbor_mean;
+        site.clamp_invariants();
+    }
+}
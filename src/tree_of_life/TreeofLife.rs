@@ -1,9 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-// Import your actual types; adjust paths to match your repo layout.
-use crate::policy::capability::CapabilityState;
-use crate::policy::roh::RoHScore;
-use crate::biophysical::envelope::BiophysicalEnvelopeSnapshot;
+use aln_core::{BiophysicalEnvelopeSnapshot, CapabilityState, RoHScore};
 
 /// Tree-of-Life scalar asset view (all values normalized 0.0–1.0).
 ///
@@ -143,12 +140,8 @@ impl TreeOfLife {
     fn clamp01(x: f32) -> f32 {
         if x.is_nan() {
             0.0
-        } else if x < 0.0 {
-            0.0
-        } else if x > 1.0 {
-            1.0
         } else {
-            x
+            x.clamp(0.0, 1.0)
         }
     }
 
@@ -184,16 +177,14 @@ impl TreeOfLife {
             .unwrap_or(0.0);
 
         // Decay: how close we are to RoH ceiling (0.3).
-        let roh_norm = (roh.value / 0.3).min(1.0).max(0.0);
-        let decay = roh_norm;
+        let decay = roh.normalized();
 
         (time, decay)
     }
 
     fn map_lifeforce(roh: &RoHScore) -> f32 {
         // Within RoH ≤ 0.3, lifeforce = 1 - (RoH / 0.3); clamp in case of overshoot.
-        let roh_norm = (roh.value / 0.3).min(1.0).max(0.0);
-        let lf = 1.0 - roh_norm;
+        let lf = 1.0 - roh.normalized();
         Self::clamp01(lf)
     }
 
@@ -225,8 +216,7 @@ impl TreeOfLife {
         env: &BiophysicalEnvelopeSnapshot,
         cap: CapabilityState,
     ) -> (f32, f32) {
-        // POWER: proportion of axes in WARN/RISK vs INFO.
-        let info_frac = env.info_axis_fraction.unwrap_or(1.0);
+        // POWER: proportion of axes in WARN/RISK.
         let warn_frac = env.warn_axis_fraction.unwrap_or(0.0);
         let risk_frac = env.risk_axis_fraction.unwrap_or(0.0);
 
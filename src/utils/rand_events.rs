@@ -2,13 +2,18 @@ use rand::Rng;
 
 /// Bounded ecological event types for advisory sandbox use only.[file:1]
 pub fn generate_ecological_event() -> String {
+    generate_ecological_event_with(&mut rand::thread_rng())
+}
+
+/// Same as `generate_ecological_event`, drawing from a caller-supplied RNG
+/// so seeded runs are reproducible.
+pub fn generate_ecological_event_with<R: Rng + ?Sized>(rng: &mut R) -> String {
     let events = [
         "ecological_sharing",
         "resource_aid",
         "minor_disturbance",
         "math_science_education",
     ];
-    let mut rng = rand::thread_rng();
     let idx = rng.gen_range(0..events.len());
     events[idx].to_string()
 }
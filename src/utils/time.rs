@@ -1,11 +1,109 @@
 use chrono::Utc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Default discount time constant: one day, in seconds.
+pub const DEFAULT_DISCOUNT_TAU_SECONDS: f64 = 86_400.0;
+
+/// Source of "now" as Unix seconds, so time-dependent results can be reproduced.
+pub trait Clock {
+    fn now(&self) -> u64;
+}
+
+/// Wall-clock time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        Utc::now().timestamp() as u64
+    }
+}
+
+/// Always reports the same instant; use to evaluate a ledger "as of" a given time.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Manually advanced clock for simulations; share it via `Arc` to drive
+/// several consumers from one timeline.
+#[derive(Debug, Default)]
+pub struct SimulatedClock {
+    now: AtomicU64,
+}
+
+impl SimulatedClock {
+    pub fn starting_at(now: u64) -> Self {
+        Self {
+            now: AtomicU64::new(now),
+        }
+    }
+
+    pub fn advance(&self, seconds: u64) {
+        self.now.fetch_add(seconds, Ordering::SeqCst);
+    }
+
+    pub fn set(&self, now: u64) {
+        self.now.store(now, Ordering::SeqCst);
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> u64 {
+        self.now.load(Ordering::SeqCst)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> u64 {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> u64 {
+        (**self).now()
+    }
+}
+
+/// A discount time constant that is zero, negative or not finite.
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+#[error("discount time constant must be finite and positive, got {0}")]
+pub struct InvalidDiscountTau(pub f64);
+
+/// Accept `tau_seconds` only if it is finite and strictly positive.
+pub fn validate_discount_tau(tau_seconds: f64) -> Result<f64, InvalidDiscountTau> {
+    if tau_seconds.is_finite() && tau_seconds > 0.0 {
+        Ok(tau_seconds)
+    } else {
+        Err(InvalidDiscountTau(tau_seconds))
+    }
+}
 
 /// Simple exponential time discount with 1 day time constant, age in seconds.[file:2]
 pub fn time_discount_factor(age_seconds: u64) -> f64 {
-    let tau = 86_400.0;
-    (-(age_seconds as f64) / tau).exp()
+    discount(age_seconds, DEFAULT_DISCOUNT_TAU_SECONDS)
+}
+
+/// Exponential time discount with a caller-chosen time constant `tau_seconds`.
+pub fn time_discount_factor_with_tau(
+    age_seconds: u64,
+    tau_seconds: f64,
+) -> Result<f64, InvalidDiscountTau> {
+    validate_discount_tau(tau_seconds).map(|tau| discount(age_seconds, tau))
+}
+
+/// Discount for an already validated `tau_seconds`.
+pub(crate) fn discount(age_seconds: u64, tau_seconds: f64) -> f64 {
+    (-(age_seconds as f64) / tau_seconds).exp()
 }
 
 pub fn current_timestamp() -> u64 {
-    Utc::now().timestamp() as u64
+    SystemClock.now()
 }
@@ -0,0 +1,155 @@
+// src/world_invariants.rs
+// World-level (global) invariants over the whole Jetson-Line.
+//
+// Local checks in deeds.rs keep every site inside its own corridors, but a
+// line of sites that each satisfy POWER <= k * CHURCH can still break the
+// doctrine in aggregate. These checks sum over all sites:
+// - global POWER budget: sum(POWER) <= ratio * sum(CHURCH),
+// - total BIOLOAD ceiling,
+// - aggregate (mean) FEAR ceiling.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::deeds::WorldLine;
+use crate::tokens::ExtendedTokenState;
+
+/// Limits for the world-level invariants.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GlobalInvariantParams {
+    /// sum(POWER) <= power_church_ratio * sum(CHURCH); the doctrine uses 1.0.
+    pub power_church_ratio: f64,
+    pub bioload_max_total: f64,
+    pub fear_max_mean: f64,
+}
+
+/// Aggregates the global invariants are checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WorldTotals {
+    pub power: f64,
+    pub church: f64,
+    pub bioload: f64,
+    pub fear_mean: f64,
+}
+
+impl WorldTotals {
+    pub fn of(world: &WorldLine) -> Self {
+        let mut totals = Self {
+            power: 0.0,
+            church: 0.0,
+            bioload: 0.0,
+            fear_mean: 0.0,
+        };
+        for site in &world.sites {
+            totals.power += site.power;
+            totals.church += site.church;
+            totals.bioload += site.bioload;
+            totals.fear_mean += site.fear;
+        }
+        if !world.sites.is_empty() {
+            totals.fear_mean /= world.sites.len() as f64;
+        }
+        totals
+    }
+
+    /// Update in place for one site of a `num_sites` world going from `pre` to `post`.
+    pub fn apply_change(
+        &mut self,
+        pre: &ExtendedTokenState,
+        post: &ExtendedTokenState,
+        num_sites: usize,
+    ) {
+        self.power += post.power - pre.power;
+        self.church += post.church - pre.church;
+        self.bioload += post.bioload - pre.bioload;
+        if num_sites > 0 {
+            self.fear_mean += (post.fear - pre.fear) / num_sites as f64;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GlobalInvariant {
+    PowerBudget,
+    TotalBioload,
+    AggregateFear,
+}
+
+impl fmt::Display for GlobalInvariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlobalInvariant::PowerBudget => write!(f, "global POWER budget"),
+            GlobalInvariant::TotalBioload => write!(f, "total BIOLOAD ceiling"),
+            GlobalInvariant::AggregateFear => write!(f, "aggregate FEAR ceiling"),
+        }
+    }
+}
+
+/// One global invariant that does not hold, with its limit and observed value.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GlobalViolation {
+    pub invariant: GlobalInvariant,
+    pub limit: f64,
+    pub observed: f64,
+}
+
+impl fmt::Display for GlobalViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} exceeded ({:.3} > {:.3})",
+            self.invariant, self.observed, self.limit
+        )
+    }
+}
+
+impl GlobalInvariantParams {
+    /// Every global invariant `totals` violates.
+    pub fn check(&self, totals: &WorldTotals) -> Vec<GlobalViolation> {
+        let checks = [
+            (
+                GlobalInvariant::PowerBudget,
+                self.power_church_ratio * totals.church,
+                totals.power,
+            ),
+            (
+                GlobalInvariant::TotalBioload,
+                self.bioload_max_total,
+                totals.bioload,
+            ),
+            (
+                GlobalInvariant::AggregateFear,
+                self.fear_max_mean,
+                totals.fear_mean,
+            ),
+        ];
+        checks
+            .into_iter()
+            .filter(|(_, limit, observed)| observed > limit)
+            .map(|(invariant, limit, observed)| GlobalViolation {
+                invariant,
+                limit,
+                observed,
+            })
+            .collect()
+    }
+
+    /// Violations in `after` that a change from `before` introduced or made worse.
+    ///
+    /// A world already out of bounds may still take deeds that move it back.
+    pub fn breaches(&self, before: &WorldTotals, after: &WorldTotals) -> Vec<GlobalViolation> {
+        let excess = |v: &GlobalViolation| v.observed - v.limit;
+        let prior = self.check(before);
+        self.check(after)
+            .into_iter()
+            .filter(|v| {
+                prior
+                    .iter()
+                    .find(|p| p.invariant == v.invariant)
+                    .is_none_or(|p| excess(v) > excess(p))
+            })
+            .collect()
+    }
+}
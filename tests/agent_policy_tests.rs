@@ -0,0 +1,111 @@
+mod common;
+
+use microsociety_tree_of_life::deeds::{DeedRequest, DeedType};
+use microsociety_tree_of_life::simulation::{
+    AggressorPolicy, AltruisticPolicy, MicroSociety, ScriptedPolicy, TitForTatPolicy,
+};
+
+fn site_society(n: usize) -> MicroSociety {
+    common::site_society(n, 5)
+}
+
+fn original_types(society: &MicroSociety, actor: &str) -> Vec<String> {
+    society
+        .ledger
+        .query()
+        .actor(actor)
+        .iter()
+        .map(|e| {
+            e.context_json["validated_deed"]["original_type"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn test_mixed_policies_in_one_society() {
+    let mut society = site_society(4);
+    assert!(society.set_policy(0, Box::new(AggressorPolicy)));
+    assert!(society.set_policy(1, Box::new(AltruisticPolicy::default())));
+    assert!(society.set_policy(2, Box::new(TitForTatPolicy::default())));
+    assert!(!society.set_policy(9, Box::new(AggressorPolicy)));
+
+    for _ in 0..3 {
+        society.simulate_cycle().unwrap();
+    }
+
+    assert!(original_types(&society, "agent_0")
+        .iter()
+        .all(|t| t == "Conflict"));
+    assert!(original_types(&society, "agent_1")
+        .iter()
+        .all(|t| t == "Help" || t == "Repair"));
+    // Agent 0's conflict drained site 1's TRUST before agent 2 moved.
+    assert_eq!(original_types(&society, "agent_2")[0], "Conflict");
+    let first = society
+        .ledger
+        .query()
+        .actor("agent_2")
+        .iter()
+        .next()
+        .unwrap();
+    assert_eq!(first.target_ids, vec!["agent_1".to_string()]);
+}
+
+#[test]
+fn test_tit_for_tat_opens_with_help() {
+    let mut society = site_society(3);
+    for agent in 0..3 {
+        society.set_policy(agent, Box::new(TitForTatPolicy::default()));
+    }
+    society.simulate_cycle().unwrap();
+
+    for agent in ["agent_0", "agent_1", "agent_2"] {
+        assert_eq!(original_types(&society, agent), vec!["Help".to_string()]);
+    }
+}
+
+#[test]
+fn test_scripted_policy_replays_then_idles() {
+    let step = |deed_type, target_site, intensity| DeedRequest {
+        tick: 0,
+        deed_id: 0,
+        proposer: 0,
+        deed_type,
+        source_site: 0,
+        target_site,
+        intensity,
+    };
+    let mut society = site_society(3);
+    society.set_policy(
+        1,
+        Box::new(ScriptedPolicy::new([
+            step(DeedType::DeployTech, None, 0.4),
+            step(DeedType::Help, Some(2), 0.3),
+        ])),
+    );
+    for _ in 0..3 {
+        society.simulate_cycle().unwrap();
+    }
+
+    let events: Vec<_> = society.ledger.query().actor("agent_1").iter().collect();
+    let validated: Vec<_> = events
+        .iter()
+        .map(|e| &e.context_json["validated_deed"])
+        .collect();
+    assert_eq!(validated[0]["original_type"], "DeployTech");
+    assert_eq!(validated[0]["source_site"], 1);
+    assert_eq!(validated[1]["original_type"], "Help");
+    assert_eq!(validated[1]["target_site"], 2);
+    assert_eq!(validated[1]["tick"], 1);
+    assert_eq!(validated[2]["original_type"], "Repair");
+    assert_eq!(events[2].target_ids, Vec::<String>::new());
+}
+
+#[test]
+fn test_set_policy_outside_site_mode_is_refused() {
+    let mut society = MicroSociety::with_seed(3, 1);
+    assert!(!society.set_policy(0, Box::new(AggressorPolicy)));
+}
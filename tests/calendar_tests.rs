@@ -0,0 +1,132 @@
+use approx::assert_relative_eq;
+
+use microsociety_tree_of_life::ledger::ChurchAccountState;
+use microsociety_tree_of_life::simulation::{CalendarBehindLedger, MicroSociety, SimCalendar};
+use microsociety_tree_of_life::utils::time::FixedClock;
+
+const EPOCH: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+fn society_on_calendar(cycles: usize) -> MicroSociety {
+    let mut society = MicroSociety::with_seed(3, 11);
+    society.set_calendar(SimCalendar::new(EPOCH, DAY)).unwrap();
+    for _ in 0..cycles {
+        society.simulate_cycle().unwrap();
+    }
+    society
+}
+
+#[test]
+fn test_events_carry_tick_and_calendar_timestamp() {
+    let society = society_on_calendar(4);
+    assert_eq!(society.tick(), 4);
+    assert_eq!(society.now(), EPOCH + 4 * DAY);
+
+    // Three agents act once per cycle, all stamped with the cycle's day.
+    let stamps: Vec<(Option<u64>, u64)> = society
+        .ledger
+        .all_events()
+        .iter()
+        .map(|e| (e.tick, e.timestamp))
+        .collect();
+    let expected: Vec<(Option<u64>, u64)> = [0, 1, 2, 3]
+        .into_iter()
+        .flat_map(|tick| [(Some(tick), 1_700_000_000 + tick * 86_400); 3])
+        .collect();
+    assert_eq!(stamps, expected);
+    assert_eq!(stamps[11], (Some(3), 1_700_259_200));
+}
+
+#[test]
+fn test_adopted_ledger_resumes_after_last_tick() {
+    let first = society_on_calendar(2);
+
+    let mut resumed = MicroSociety::with_seed(3, 12);
+    resumed.set_calendar(SimCalendar::new(EPOCH, DAY)).unwrap();
+    resumed.adopt_ledger(first.ledger.clone()).unwrap();
+    assert_eq!(resumed.tick(), 2);
+    resumed.simulate_cycle().unwrap();
+
+    let last = resumed.ledger.all_events().last().unwrap();
+    assert_eq!(last.tick, Some(2));
+    assert_eq!(last.timestamp, 1_700_172_800);
+    assert!(resumed.ledger.verify().is_valid());
+}
+
+#[test]
+fn test_calendar_behind_adopted_ledger_is_refused() {
+    let first = society_on_calendar(2);
+
+    // Adopt first, then try to move the calendar back a year.
+    let mut resumed = MicroSociety::with_seed(3, 12);
+    resumed.set_calendar(SimCalendar::new(EPOCH, DAY)).unwrap();
+    resumed.adopt_ledger(first.ledger.clone()).unwrap();
+    let err = resumed
+        .set_calendar(SimCalendar::new(EPOCH - 365 * DAY, DAY))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        CalendarBehindLedger {
+            tick: 2,
+            timestamp: 1_668_636_800,
+            last_timestamp: 1_700_086_400,
+        }
+    );
+    // The society keeps its calendar and can still append.
+    assert_eq!(resumed.now(), 1_700_172_800);
+    resumed.simulate_cycle().unwrap();
+
+    // Calendar first, then adopt: refused the same way, ledger not taken.
+    let mut behind = MicroSociety::with_seed(3, 12);
+    behind
+        .set_calendar(SimCalendar::new(EPOCH - 365 * DAY, DAY))
+        .unwrap();
+    assert_eq!(behind.adopt_ledger(first.ledger.clone()), Err(err));
+    assert!(behind.ledger.all_events().is_empty());
+    assert_eq!(behind.tick(), 0);
+
+    // Catching up exactly to the last event is allowed.
+    let mut level = MicroSociety::with_seed(3, 12);
+    level
+        .set_calendar(SimCalendar::new(EPOCH - DAY, DAY))
+        .unwrap();
+    level.adopt_ledger(first.ledger.clone()).unwrap();
+    assert_eq!(level.now(), 1_700_086_400);
+    level.simulate_cycle().unwrap();
+}
+
+#[test]
+fn test_discounting_follows_simulated_history() {
+    let society = society_on_calendar(30);
+    let score_at = |t: u64| {
+        ChurchAccountState::compute_from_ledger_with_clock(
+            &society.ledger,
+            "agent_0",
+            &FixedClock(t),
+            DAY as f64,
+        )
+        .unwrap()
+        .unwrap()
+    };
+
+    // With tau one day, a deed from tick t counts e^-(30 - t) at the end of day 30.
+    assert_eq!(society.now(), 1_702_592_000);
+    let good_ticks: Vec<u64> = society
+        .ledger
+        .events_for_actor("agent_0")
+        .filter(|e| e.is_good_deed())
+        .map(|e| e.tick.unwrap())
+        .collect();
+    assert!(!good_ticks.is_empty());
+    let expected: f64 = good_ticks.iter().map(|&t| (-((30 - t) as f64)).exp()).sum();
+    let now = score_at(1_702_592_000);
+    assert_relative_eq!(now.cumulative_good_deeds, expected, max_relative = 1e-12);
+
+    // Thirty simulated days later, every deed has decayed by a further e^-30.
+    let later = score_at(1_702_592_000 + 30 * 86_400);
+    assert_relative_eq!(
+        later.cumulative_good_deeds,
+        expected * (-30.0f64).exp(),
+        max_relative = 1e-12
+    );
+}
@@ -0,0 +1,50 @@
+//! Fixtures shared by the integration tests.
+//!
+//! Each test crate compiles this module separately and uses only part of it.
+#![allow(dead_code)]
+
+use microsociety_tree_of_life::deeds::{InvariantParams, WorldLine};
+use microsociety_tree_of_life::simulation::MicroSociety;
+use microsociety_tree_of_life::tokens::ExtendedTokenState;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+pub fn invariants() -> InvariantParams {
+    InvariantParams {
+        roh_max: 0.3,
+        decay_max: 1.0,
+        power_church_k: 1.0,
+        fear_min: 0.0,
+        fear_max: 0.8,
+        sovereignty_min_invasive: 0.2,
+        bioload_max_site: 1.0,
+        justice_min: 0.1,
+    }
+}
+
+/// A site comfortably inside every corridor of `invariants()`.
+pub fn healthy_site() -> ExtendedTokenState {
+    let mut site = ExtendedTokenState::zero();
+    site.church = 1.0;
+    site.power = 0.5;
+    site.life = 1.0;
+    site.lifeforce = 0.8;
+    site.justice = 0.5;
+    site.sovereignty = 0.6;
+    site
+}
+
+pub fn world(n: usize) -> WorldLine {
+    WorldLine {
+        sites: vec![healthy_site(); n],
+    }
+}
+
+/// Site-backed society over `world(n)` with a seeded rng.
+pub fn site_society(n: usize, seed: u64) -> MicroSociety {
+    MicroSociety::with_world_line(
+        world(n),
+        invariants(),
+        Box::new(StdRng::seed_from_u64(seed)),
+    )
+}
@@ -0,0 +1,197 @@
+use std::fs;
+
+use microsociety_tree_of_life::deeds::{DeedRequest, DeedType, WorldLine};
+use microsociety_tree_of_life::doctrine::{Doctrine, DoctrineError};
+use microsociety_tree_of_life::jetson_line::JetsonLineSim;
+use microsociety_tree_of_life::tokens::ExtendedTokenState;
+use microsociety_tree_of_life::utils::crypto::compute_sha256_hash;
+
+fn reference_path() -> String {
+    format!("{}/doctrines/jetson_line.toml", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn reference_text() -> String {
+    fs::read_to_string(reference_path()).unwrap()
+}
+
+fn world() -> WorldLine {
+    let mut site = ExtendedTokenState::zero();
+    site.church = 1.0;
+    site.power = 0.5;
+    site.life = 1.0;
+    site.lifeforce = 0.8;
+    site.justice = 0.5;
+    site.sovereignty = 0.6;
+    WorldLine {
+        sites: vec![site; 3],
+    }
+}
+
+#[test]
+fn test_reference_doctrine_populates_every_section() {
+    let doctrine = Doctrine::load(reference_path()).unwrap();
+
+    assert_eq!(doctrine.invariants.roh_max, 0.3);
+    assert_eq!(doctrine.spectral.fear_window_length, 3);
+    assert_eq!(doctrine.vocab.hpcc_high, 0.7);
+    assert_eq!(doctrine.global.unwrap().bioload_max_total, 10.0);
+    assert_eq!(doctrine.transport.unwrap().exposure_neighbor, 0.3);
+    assert_eq!(
+        doctrine.content_hash(),
+        compute_sha256_hash(reference_text().as_bytes())
+    );
+}
+
+#[test]
+fn test_json_doctrine_matches_toml_doctrine() {
+    let toml_doctrine = Doctrine::from_toml_str(&reference_text()).unwrap();
+    let json = serde_json::to_string(&toml_doctrine).unwrap();
+    let json_doctrine = Doctrine::from_json_str(&json).unwrap();
+
+    assert_eq!(
+        json_doctrine.invariants.fear_max,
+        toml_doctrine.invariants.fear_max
+    );
+    assert_eq!(json_doctrine.vocab.tecr_high, toml_doctrine.vocab.tecr_high);
+    // The hash is over the source bytes, so the two encodings differ.
+    assert_ne!(json_doctrine.content_hash(), toml_doctrine.content_hash());
+}
+
+#[test]
+fn test_schema_violations_are_rejected() {
+    let text = reference_text();
+
+    let loose = text.replace("roh_max = 0.3", "roh_max = 0.35");
+    assert!(matches!(
+        Doctrine::from_toml_str(&loose),
+        Err(DoctrineError::RohMaxTooHigh { .. })
+    ));
+
+    let inverted = text.replace("fear_min = 0.0", "fear_min = 0.9");
+    assert!(matches!(
+        Doctrine::from_toml_str(&inverted),
+        Err(DoctrineError::InvertedBand { .. })
+    ));
+
+    let negative = text.replace("bioload_max_total = 10.0", "bioload_max_total = -1.0");
+    match Doctrine::from_toml_str(&negative) {
+        Err(DoctrineError::NegativeCeiling { field, .. }) => {
+            assert_eq!(field, "global.bioload_max_total")
+        }
+        other => panic!("expected NegativeCeiling, got {:?}", other),
+    }
+
+    let unknown = format!("{}\n[extra]\nkey = 1\n", text);
+    assert!(matches!(
+        Doctrine::from_toml_str(&unknown),
+        Err(DoctrineError::Toml(_))
+    ));
+}
+
+#[test]
+fn test_diffusion_rates_must_be_fractions() {
+    let text = reference_text();
+    for (from, to, expected) in [
+        (
+            "pollution_diffusion = 0.2",
+            "pollution_diffusion = 1.5",
+            "transport.pollution_diffusion",
+        ),
+        (
+            "fear_diffusion = 0.1",
+            "fear_diffusion = -0.1",
+            "transport.fear_diffusion",
+        ),
+        (
+            "fear_diffusion = 0.1",
+            "fear_diffusion = nan",
+            "transport.fear_diffusion",
+        ),
+    ] {
+        match Doctrine::from_toml_str(&text.replace(from, to)) {
+            Err(DoctrineError::RateOutOfRange { field, .. }) => assert_eq!(field, expected),
+            other => panic!("{}: expected RateOutOfRange, got {:?}", to, other),
+        }
+    }
+
+    let edges = text
+        .replace("pollution_diffusion = 0.2", "pollution_diffusion = 0.0")
+        .replace("fear_diffusion = 0.1", "fear_diffusion = 1.0");
+    assert!(Doctrine::from_toml_str(&edges).is_ok());
+}
+
+#[test]
+fn test_exposure_weights_must_not_be_negative() {
+    let text = reference_text();
+    for (from, to, expected) in [
+        (
+            "exposure_local = 0.7",
+            "exposure_local = -0.7",
+            "transport.exposure_local",
+        ),
+        (
+            "exposure_neighbor = 0.3",
+            "exposure_neighbor = inf",
+            "transport.exposure_neighbor",
+        ),
+    ] {
+        match Doctrine::from_toml_str(&text.replace(from, to)) {
+            Err(DoctrineError::NegativeWeight { field, .. }) => assert_eq!(field, expected),
+            other => panic!("{}: expected NegativeWeight, got {:?}", to, other),
+        }
+    }
+
+    let zero = text.replace("exposure_neighbor = 0.3", "exposure_neighbor = 0.0");
+    assert!(Doctrine::from_toml_str(&zero).is_ok());
+}
+
+#[test]
+fn test_misspelled_nested_keys_are_rejected() {
+    let text = reference_text();
+    let typos = [
+        ("[invariants]", "roh_maximum = 0.1"),
+        ("[spectral]", "fear_hihg_band = 0.5"),
+        ("[vocab]", "hpcc_hi = 0.9"),
+        ("[global]", "bioload_max_totl = 1.0"),
+        ("[transport]", "fear_difusion = 0.0"),
+    ];
+    for (table, typo) in typos {
+        let misspelled = text.replace(table, &format!("{}\n{}", table, typo));
+        match Doctrine::from_toml_str(&misspelled) {
+            Err(DoctrineError::Toml(e)) => assert!(e.to_string().contains("unknown field")),
+            other => panic!(
+                "{} {}: expected unknown field, got {:?}",
+                table, typo, other
+            ),
+        }
+    }
+
+    let doctrine = Doctrine::from_toml_str(&text).unwrap();
+    let mut json = serde_json::to_value(&doctrine).unwrap();
+    json["invariants"]["justice_minimum"] = serde_json::json!(0.5);
+    assert!(matches!(
+        Doctrine::from_json_str(&json.to_string()),
+        Err(DoctrineError::Json(_))
+    ));
+}
+
+#[test]
+fn test_records_carry_doctrine_hash() {
+    let doctrine = Doctrine::load(reference_path()).unwrap();
+    let mut sim = JetsonLineSim::from_doctrine(world(), &doctrine);
+    sim.submit(DeedRequest {
+        tick: 0,
+        deed_id: 0,
+        proposer: 0,
+        deed_type: DeedType::Help,
+        source_site: 0,
+        target_site: Some(1),
+        intensity: 0.5,
+    });
+    let report = sim.step();
+
+    let hash = Some(doctrine.content_hash().to_string());
+    assert_eq!(report.doctrine_hash, hash);
+    assert_eq!(report.deeds.len(), 1);
+    assert_eq!(report.deeds[0].doctrine_hash, hash);
+}
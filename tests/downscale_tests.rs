@@ -0,0 +1,83 @@
+use approx::assert_relative_eq;
+
+mod common;
+
+use common::{invariants, world};
+use microsociety_tree_of_life::deeds::{
+    DeedRequest, DeedStatus, DeedType, ValidationKernel, WorldLine,
+};
+use microsociety_tree_of_life::world_invariants::GlobalInvariantParams;
+
+fn world_with_bioload(bioload: f64) -> WorldLine {
+    let mut world = world(2);
+    for site in &mut world.sites {
+        site.bioload = bioload;
+    }
+    world
+}
+
+fn deploy_tech(intensity: f64) -> DeedRequest {
+    DeedRequest {
+        tick: 0,
+        deed_id: 0,
+        proposer: 0,
+        deed_type: DeedType::DeployTech,
+        source_site: 0,
+        target_site: None,
+        intensity,
+    }
+}
+
+#[test]
+fn test_safe_deed_keeps_requested_intensity() {
+    let kernel = ValidationKernel::new(invariants());
+    let mut world = world_with_bioload(0.5);
+    let deed = kernel.process_deed(&mut world, deploy_tech(0.8)).unwrap();
+
+    assert_eq!(deed.status, DeedStatus::Success);
+    assert_eq!(deed.requested_intensity, 0.8);
+    assert_eq!(deed.intensity, 0.8);
+}
+
+#[test]
+fn test_ceiling_breach_is_downscaled_to_largest_safe_intensity() {
+    let kernel = ValidationKernel::new(invariants());
+    // DeployTech adds 0.02 * intensity BIOLOAD, so only half the deed fits.
+    let mut world = world_with_bioload(0.99);
+    let deed = kernel.process_deed(&mut world, deploy_tech(1.0)).unwrap();
+
+    assert_eq!(deed.status, DeedStatus::Transformed);
+    assert_eq!(deed.deed_type, DeedType::DeployTech);
+    assert!(deed.reason.starts_with("Downscaled"));
+    assert_eq!(deed.requested_intensity, 1.0);
+    assert_relative_eq!(deed.intensity, 0.5, epsilon = 1e-9);
+    assert!(world.sites[0].bioload <= 1.0);
+    assert_relative_eq!(world.sites[0].bioload, 1.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_no_safe_intensity_falls_back_to_transform() {
+    let kernel = ValidationKernel::new(invariants());
+    let mut world = world_with_bioload(1.2);
+    let deed = kernel.process_deed(&mut world, deploy_tech(1.0)).unwrap();
+
+    assert_eq!(deed.status, DeedStatus::Transformed);
+    assert_eq!(deed.deed_type, DeedType::Repair);
+    assert!(deed.reason.starts_with("Transformed to Repair"));
+}
+
+#[test]
+fn test_global_breach_is_downscaled() {
+    let mut kernel = ValidationKernel::new(invariants());
+    kernel.global = Some(GlobalInvariantParams {
+        power_church_ratio: 1.0,
+        bioload_max_total: 1.01,
+        fear_max_mean: 1.0,
+    });
+    let mut world = world_with_bioload(0.5);
+    let deed = kernel.process_deed(&mut world, deploy_tech(1.0)).unwrap();
+
+    assert_eq!(deed.status, DeedStatus::Transformed);
+    assert!(deed.reason.contains("total BIOLOAD ceiling"));
+    assert_relative_eq!(deed.intensity, 0.5, epsilon = 1e-9);
+}
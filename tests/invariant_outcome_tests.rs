@@ -0,0 +1,96 @@
+use approx::assert_relative_eq;
+
+mod common;
+
+use common::{healthy_site, invariants};
+use microsociety_tree_of_life::deeds::{
+    DeedRequest, DeedStatus, DeedType, InvariantOutcome, ValidatedDeed, ValidationKernel, WorldLine,
+};
+
+fn world(bioload: f64, sovereignty: f64) -> WorldLine {
+    let mut site = healthy_site();
+    site.sovereignty = sovereignty;
+    site.bioload = bioload;
+    WorldLine {
+        sites: vec![site; 2],
+    }
+}
+
+fn request(deed_type: DeedType, intensity: f64) -> DeedRequest {
+    DeedRequest {
+        tick: 0,
+        deed_id: 0,
+        proposer: 0,
+        deed_type,
+        source_site: 0,
+        target_site: None,
+        intensity,
+    }
+}
+
+#[test]
+fn test_clean_deed_has_no_outcomes() {
+    let kernel = ValidationKernel::new(invariants());
+    let mut world = world(0.5, 0.6);
+    let deed = kernel
+        .process_deed(&mut world, request(DeedType::DeployTech, 0.5))
+        .unwrap();
+
+    assert_eq!(deed.status, DeedStatus::Success);
+    assert!(deed.outcomes.is_empty());
+    assert_eq!(deed.reason, "OK");
+}
+
+#[test]
+fn test_downscaled_deed_records_ceiling_threshold_and_observed() {
+    let kernel = ValidationKernel::new(invariants());
+    let mut world = world(0.99, 0.6);
+    let deed = kernel
+        .process_deed(&mut world, request(DeedType::DeployTech, 1.0))
+        .unwrap();
+
+    assert_eq!(deed.status, DeedStatus::Transformed);
+    let ceilings: Vec<_> = deed
+        .outcomes
+        .iter()
+        .filter(|o| matches!(o, InvariantOutcome::BioloadCeiling { .. }))
+        .collect();
+    assert_eq!(ceilings.len(), 1);
+    assert_eq!(ceilings[0].threshold(), 1.0);
+    // DeployTech adds 0.02 * intensity BIOLOAD at the requested intensity.
+    assert_relative_eq!(ceilings[0].observed(), 1.01, epsilon = 1e-9);
+    assert!(ceilings[0].is_violation());
+    assert_eq!(deed.reason, deed.render_reason());
+}
+
+#[test]
+fn test_blocked_deed_reports_only_blocking_outcome() {
+    let kernel = ValidationKernel::new(invariants());
+    let mut world = world(0.5, 0.1);
+    let deed = kernel
+        .process_deed(&mut world, request(DeedType::DeployTech, 0.5))
+        .unwrap();
+
+    assert_eq!(deed.status, DeedStatus::Blocked);
+    assert_eq!(
+        deed.outcomes,
+        vec![InvariantOutcome::SovereigntyTooLow {
+            threshold: 0.2,
+            observed: 0.1,
+        }]
+    );
+    assert!(deed.reason.starts_with("Blocked: inadequate SOVEREIGNTY"));
+}
+
+#[test]
+fn test_outcomes_roundtrip_through_json() {
+    let kernel = ValidationKernel::new(invariants());
+    let mut world = world(0.99, 0.6);
+    let deed = kernel
+        .process_deed(&mut world, request(DeedType::DeployTech, 1.0))
+        .unwrap();
+
+    let json = serde_json::to_string(&deed).unwrap();
+    let back: ValidatedDeed = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.outcomes, deed.outcomes);
+}
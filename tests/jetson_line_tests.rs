@@ -0,0 +1,81 @@
+mod common;
+
+use common::{invariants, world};
+use microsociety_tree_of_life::deeds::{DeedRequest, DeedStatus, DeedType};
+use microsociety_tree_of_life::jetson_line::JetsonLineSim;
+use microsociety_tree_of_life::spectral::SpectralParams;
+
+fn spectral_params() -> SpectralParams {
+    SpectralParams {
+        death_high: 1.0,
+        life_low: 1e-9,
+        decay_high: 0.8,
+        lifeforce_low: 0.1,
+        justice_low: 0.1,
+        habit_high: 5.0,
+        fear_low_band: 0.0,
+        fear_high_band: 0.6,
+        fear_window_length: 3,
+        twistof_fate_window: 10,
+        twistof_fate_severity_threshold: 0.5,
+        bioload_max_site: 1.0,
+    }
+}
+
+fn request(
+    tick: u64,
+    deed_id: u64,
+    deed_type: DeedType,
+    source: u64,
+    target: Option<u64>,
+) -> DeedRequest {
+    DeedRequest {
+        tick,
+        deed_id,
+        proposer: source,
+        deed_type,
+        source_site: source,
+        target_site: target,
+        intensity: 0.5,
+    }
+}
+
+fn scripted_run() -> String {
+    let mut sim = JetsonLineSim::new(world(4), invariants(), spectral_params());
+    sim.submit(request(0, 1, DeedType::Help, 0, Some(1)));
+    sim.submit(request(0, 2, DeedType::EmitPollution, 2, Some(3)));
+    sim.submit(request(1, 3, DeedType::Conflict, 1, Some(2)));
+    sim.submit(request(2, 4, DeedType::Repair, 3, None));
+    let reports = sim.run(3);
+    serde_json::to_string(&reports).unwrap()
+}
+
+#[test]
+fn test_jetson_line_is_deterministic() {
+    assert_eq!(scripted_run(), scripted_run());
+}
+
+#[test]
+fn test_future_requests_wait_for_their_tick() {
+    let mut sim = JetsonLineSim::new(world(3), invariants(), spectral_params());
+    sim.submit(request(2, 1, DeedType::Repair, 0, None));
+
+    assert!(sim.step().deeds.is_empty());
+    assert!(sim.step().deeds.is_empty());
+    let report = sim.step();
+    assert_eq!(report.tick, 2);
+    assert_eq!(report.deeds.len(), 1);
+    assert_eq!(report.deeds[0].status, DeedStatus::Success);
+    assert_eq!(sim.pending(), 0);
+    assert_eq!(sim.tick(), 3);
+}
+
+#[test]
+fn test_unknown_sites_are_dropped_not_applied() {
+    let mut sim = JetsonLineSim::new(world(2), invariants(), spectral_params());
+    sim.submit(request(0, 1, DeedType::Help, 0, Some(7)));
+    let report = sim.step();
+    assert!(report.deeds.is_empty());
+    assert_eq!(report.dropped.len(), 1);
+    assert_eq!(sim.world.sites[0].power, 0.5);
+}
@@ -0,0 +1,49 @@
+use microsociety_tree_of_life::simulation::MicroSociety;
+
+fn seeded_society() -> MicroSociety {
+    // 5 agents x 3 cycles = 15 events: two full batches of 7 plus a partial one.
+    let mut society = MicroSociety::with_seed(5, 21);
+    for _ in 0..3 {
+        society.simulate_cycle().unwrap();
+    }
+    society
+}
+
+#[test]
+fn test_checkpoints_cover_only_full_batches() {
+    let society = seeded_society();
+    let checkpoints = society.ledger.checkpoints(7);
+    assert_eq!(checkpoints.len(), 2);
+    assert_eq!(checkpoints[1].start_index, 7);
+    assert_eq!(checkpoints[1].end_index, 14);
+    assert_ne!(checkpoints[0].root, checkpoints[1].root);
+}
+
+#[test]
+fn test_inclusion_proofs_verify_for_every_checkpointed_event() {
+    let society = seeded_society();
+    let events = society.ledger.all_events();
+    let checkpoints = society.ledger.checkpoints(7);
+
+    for (index, event) in events.iter().enumerate().take(14) {
+        let proof = society.ledger.inclusion_proof(index, 7).unwrap();
+        let root = &checkpoints[proof.batch_index].root;
+        assert!(proof.verify(event, root), "index {}", index);
+    }
+    assert!(society.ledger.inclusion_proof(14, 7).is_none());
+}
+
+#[test]
+fn test_inclusion_proof_rejects_wrong_event_or_root() {
+    let society = seeded_society();
+    let events = society.ledger.all_events();
+    let checkpoints = society.ledger.checkpoints(7);
+    let proof = society.ledger.inclusion_proof(3, 7).unwrap();
+
+    assert!(!proof.verify(&events[4], &checkpoints[0].root));
+    assert!(!proof.verify(&events[3], &checkpoints[1].root));
+
+    let mut forged = events[3].clone();
+    forged.life_harm_flag = !forged.life_harm_flag;
+    assert!(!proof.verify(&forged, &checkpoints[0].root));
+}
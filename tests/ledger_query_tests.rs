@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use microsociety_tree_of_life::ledger::DeedEvent;
+use microsociety_tree_of_life::simulation::MicroSociety;
+use microsociety_tree_of_life::utils::time::SimulatedClock;
+
+fn seeded_society() -> MicroSociety {
+    let mut society = MicroSociety::with_seed(6, 17);
+    for _ in 0..5 {
+        society.simulate_cycle().unwrap();
+    }
+    society
+}
+
+fn ids<'a>(events: impl Iterator<Item = &'a DeedEvent>) -> Vec<&'a str> {
+    events.map(|e| e.event_id.as_str()).collect()
+}
+
+#[test]
+fn test_indexed_queries_match_linear_scan() {
+    let society = seeded_society();
+    let ledger = &society.ledger;
+    let all = ledger.all_events();
+
+    let by_actor = ids(ledger.query().actor("agent_2").iter());
+    let expected = ids(all.iter().filter(|e| e.actor_id == "agent_2"));
+    assert_eq!(by_actor, expected);
+    assert_eq!(ids(ledger.events_for_actor("agent_2")), expected);
+
+    let harmful_to_3 = ids(ledger
+        .query()
+        .target("agent_3")
+        .ethics_flag("minor_harm")
+        .tag("microlife")
+        .iter());
+    let expected = ids(all.iter().filter(|e| {
+        e.target_ids.iter().any(|t| t == "agent_3")
+            && e.ethics_flags.iter().any(|f| f == "minor_harm")
+    }));
+    assert_eq!(harmful_to_3, expected);
+
+    let aid = ids(ledger.query().deed_type("resource_aid").iter());
+    let expected = ids(all.iter().filter(|e| e.deed_type == "resource_aid"));
+    assert_eq!(aid, expected);
+}
+
+#[test]
+fn test_queries_with_unknown_keys_or_empty_window_return_nothing() {
+    let society = seeded_society();
+    let ledger = &society.ledger;
+    let first_ts = ledger.all_events()[0].timestamp;
+
+    assert_eq!(ledger.query().actor("agent_99").iter().count(), 0);
+    assert_eq!(ledger.query().tag("no_such_tag").iter().count(), 0);
+    assert_eq!(ledger.query().until(first_ts).iter().count(), 0);
+    assert_eq!(
+        ledger.query().since(first_ts).iter().count(),
+        ledger.all_events().len()
+    );
+}
+
+#[test]
+fn test_time_window_selects_exact_events() {
+    // Six agents act once per cycle; cycle c is stamped 1_000 + 10 * c.
+    let clock = Arc::new(SimulatedClock::starting_at(1_000));
+    let mut society = MicroSociety::with_seed(6, 17);
+    society.set_clock(Box::new(Arc::clone(&clock)));
+    for _ in 0..5 {
+        society.simulate_cycle().unwrap();
+        clock.advance(10);
+    }
+    let ledger = &society.ledger;
+    let all = ledger.all_events();
+    assert_eq!(all.len(), 30);
+    assert_eq!(all[6].timestamp, 1_010);
+    assert_eq!(all[17].timestamp, 1_020);
+    assert_eq!(all[18].timestamp, 1_030);
+
+    // `since` is inclusive and `until` exclusive: cycles 1 and 2.
+    let window = ids(ledger.query().since(1_010).until(1_030).iter());
+    assert_eq!(window, ids(all[6..18].iter()));
+
+    // One second either side of the edges moves a whole cycle in or out.
+    let window = ids(ledger.query().since(1_011).until(1_031).iter());
+    assert_eq!(window, ids(all[12..24].iter()));
+    let window = ids(ledger.query().since(1_009).until(1_029).iter());
+    assert_eq!(window, ids(all[6..18].iter()));
+
+    // Windows combine with the indexed filters.
+    let agent_2 = ids(ledger
+        .query()
+        .actor("agent_2")
+        .since(1_010)
+        .until(1_030)
+        .iter());
+    let expected = ids(all[6..18].iter().filter(|e| e.actor_id == "agent_2"));
+    assert_eq!(agent_2.len(), 2);
+    assert_eq!(agent_2, expected);
+}
+
+#[test]
+fn test_get_by_event_id() {
+    let society = seeded_society();
+    let event = &society.ledger.all_events()[7];
+    let found = society.ledger.get(&event.event_id).unwrap();
+    assert_eq!(found.self_hash, event.self_hash);
+    assert!(society.ledger.get("missing").is_none());
+}
@@ -0,0 +1,130 @@
+use microsociety_tree_of_life::ledger::{
+    ChainBreakKind, ChurchAccountState, FileLedger, InMemoryKeystore, Ledger, LedgerError,
+};
+use microsociety_tree_of_life::simulation::MicroSociety;
+
+/// Re-append a seeded society's events, signing those by `agent_0` with its key
+/// and leaving everyone else unsigned.
+fn partially_signed_ledger(keys: &InMemoryKeystore) -> Ledger {
+    let mut society = MicroSociety::with_seed(3, 9);
+    for _ in 0..4 {
+        society.simulate_cycle().unwrap();
+    }
+
+    let mut ledger = Ledger::new();
+    for event in society.ledger.all_events() {
+        if event.actor_id == "agent_0" {
+            ledger
+                .append_signed(event.clone(), "agent_0", keys)
+                .unwrap();
+        } else {
+            ledger.append(event.clone()).unwrap();
+        }
+    }
+    ledger
+}
+
+#[test]
+fn test_signed_events_verify_and_unknown_signer_is_refused() {
+    let mut keys = InMemoryKeystore::new();
+    keys.generate("agent_0");
+    let mut ledger = partially_signed_ledger(&keys);
+
+    assert!(ledger.verify_with_keys(&keys).is_valid());
+    assert!(ledger.all_events()[0].signature.is_some());
+    assert!(ledger.all_events()[1].signature.is_none());
+
+    let mut extra = ledger.all_events()[1].clone();
+    extra.event_id = "observer-forged".to_string();
+    extra.prev_hash = ledger.last_hash().to_string();
+    extra.self_hash = extra.compute_hash();
+    assert!(matches!(
+        ledger.append_signed(extra, "observer_9", &keys),
+        Err(LedgerError::MissingSigningKey { .. })
+    ));
+}
+
+#[test]
+fn test_signature_from_wrong_key_breaks_verification() {
+    let mut keys = InMemoryKeystore::new();
+    keys.generate("agent_0");
+    let ledger = partially_signed_ledger(&keys);
+
+    // Same signer id, different key: every agent_0 signature is now wrong.
+    let mut rotated = InMemoryKeystore::new();
+    rotated.generate("agent_0");
+    let report = ledger.verify_with_keys(&rotated);
+    let chain_break = report.first_break.unwrap();
+    assert_eq!(chain_break.index, 0);
+    assert_eq!(chain_break.kind, ChainBreakKind::InvalidSignature);
+
+    // Plain chain verification does not look at signatures.
+    assert!(ledger.verify().is_valid());
+}
+
+#[test]
+fn test_key_cannot_sign_for_another_actor() {
+    let mut keys = InMemoryKeystore::new();
+    keys.generate("agent_0");
+    keys.generate("agent_1");
+    let mut ledger = partially_signed_ledger(&keys);
+
+    let mut forged = ledger
+        .all_events()
+        .iter()
+        .rev()
+        .find(|e| e.actor_id == "agent_2")
+        .unwrap()
+        .clone();
+    forged.event_id = "forged-by-agent_1".to_string();
+    forged.prev_hash = ledger.last_hash().to_string();
+    forged.self_hash = forged.compute_hash();
+
+    assert!(matches!(
+        ledger.append_signed(forged.clone(), "agent_1", &keys),
+        Err(LedgerError::UnauthorizedSigner { .. })
+    ));
+
+    // A genuine agent_1 signature is still a forgery on an agent_2 deed.
+    assert!(forged.sign_with("agent_1", &keys));
+    assert!(!forged.has_valid_signature(&keys));
+    ledger.append(forged).unwrap();
+    let chain_break = ledger.verify_with_keys(&keys).first_break.unwrap();
+    assert_eq!(chain_break.event_id, "forged-by-agent_1");
+    assert_eq!(chain_break.kind, ChainBreakKind::InvalidSignature);
+    assert!(ChurchAccountState::compute_from_ledger_signed(&ledger, "agent_2", &keys).is_none());
+
+    // An explicitly allowed observer may co-sign.
+    keys.allow_observer("agent_2", "agent_1");
+    assert!(ledger.verify_with_keys(&keys).is_valid());
+}
+
+#[test]
+fn test_signed_rights_mode_ignores_unsigned_events() {
+    let mut keys = InMemoryKeystore::new();
+    keys.generate("agent_0");
+    let ledger = partially_signed_ledger(&keys);
+
+    assert!(ChurchAccountState::compute_from_ledger_signed(&ledger, "agent_0", &keys).is_some());
+    assert!(ChurchAccountState::compute_from_ledger(&ledger, "agent_1").is_some());
+    assert!(ChurchAccountState::compute_from_ledger_signed(&ledger, "agent_1", &keys).is_none());
+}
+
+#[test]
+fn test_signatures_survive_file_ledger_round_trip() {
+    let mut keys = InMemoryKeystore::new();
+    keys.generate("agent_0");
+    let ledger = partially_signed_ledger(&keys);
+
+    let path = std::env::temp_dir().join(format!("signed-ledger-{}.jsonl", uuid::Uuid::new_v4()));
+    let mut store = FileLedger::open(&path).unwrap();
+    for event in ledger.all_events() {
+        store.append(event.clone()).unwrap();
+    }
+    drop(store);
+
+    let reopened = FileLedger::open(&path).unwrap();
+    assert!(reopened.ledger().all_events()[0].signature.is_some());
+    assert!(reopened.ledger().verify_with_keys(&keys).is_valid());
+    std::fs::remove_file(&path).unwrap();
+}
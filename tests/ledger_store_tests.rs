@@ -0,0 +1,103 @@
+use std::fs;
+use std::path::PathBuf;
+
+use microsociety_tree_of_life::ledger::{FileLedger, LedgerStoreError};
+use microsociety_tree_of_life::simulation::MicroSociety;
+
+fn temp_ledger_path() -> PathBuf {
+    std::env::temp_dir().join(format!("deed-ledger-{}.jsonl", uuid::Uuid::new_v4()))
+}
+
+fn write_society_ledger(path: &PathBuf) -> usize {
+    let mut society = MicroSociety::with_seed(4, 11);
+    for _ in 0..3 {
+        society.simulate_cycle().unwrap();
+    }
+    let mut store = FileLedger::open(path).unwrap();
+    for event in society.ledger.all_events() {
+        store.append(event.clone()).unwrap();
+    }
+    society.ledger.all_events().len()
+}
+
+#[test]
+fn test_file_ledger_reopens_with_chain_intact() {
+    let path = temp_ledger_path();
+    let written = write_society_ledger(&path);
+
+    let reopened = FileLedger::open(&path).unwrap();
+    let events = reopened.ledger().all_events();
+    assert_eq!(events.len(), written);
+    assert_eq!(reopened.ledger().last_hash(), events[written - 1].self_hash);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_file_ledger_refuses_tampered_file() {
+    let path = temp_ledger_path();
+    write_society_ledger(&path);
+
+    let contents = fs::read_to_string(&path).unwrap();
+    let tampered = contents.replacen("minor_harm", "no_harm", 1);
+    assert_ne!(contents, tampered);
+    fs::write(&path, tampered).unwrap();
+
+    match FileLedger::open(&path) {
+        Err(LedgerStoreError::HashMismatch { .. }) => {}
+        other => panic!("expected HashMismatch, got {:?}", other.err()),
+    }
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_refused_event_leaves_file_and_memory_unchanged() {
+    let path = temp_ledger_path();
+    write_society_ledger(&path);
+    let before = fs::read_to_string(&path).unwrap();
+
+    let mut store = FileLedger::open(&path).unwrap();
+    let stored = store.ledger().all_events().len();
+    let replay = store.ledger().all_events()[0].clone();
+    assert!(matches!(
+        store.append(replay),
+        Err(LedgerStoreError::Ledger(_))
+    ));
+
+    assert_eq!(store.ledger().all_events().len(), stored);
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+
+    fs::remove_file(&path).unwrap();
+}
+
+/// What `simulate-cycles --cycles 2 --agent-count 3 --seed 1 --ledger path`
+/// does: resume the stored ledger, run, and append the new events.
+fn resume_seeded_run(path: &PathBuf) {
+    let mut society = MicroSociety::with_seed(3, 1);
+    let mut store = FileLedger::open(path).unwrap();
+    society.adopt_ledger(store.ledger().clone()).unwrap();
+    let stored = store.ledger().all_events().len();
+    for _ in 0..2 {
+        society.simulate_cycle().unwrap();
+    }
+    for event in &society.ledger.all_events()[stored..] {
+        store.append(event.clone()).unwrap();
+    }
+}
+
+#[test]
+fn test_seeded_run_resumes_onto_its_own_ledger() {
+    let path = temp_ledger_path();
+    resume_seeded_run(&path);
+    // Same seed, so the rng replays the first run's draws.
+    resume_seeded_run(&path);
+
+    let reopened = FileLedger::open(&path).unwrap();
+    let events = reopened.ledger().all_events();
+    assert_eq!(events.len(), 12);
+    assert_eq!(events[11].tick, Some(3));
+    assert!(reopened.ledger().verify().is_valid());
+
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,123 @@
+use microsociety_tree_of_life::ledger::{
+    verify_chain, verify_chain_from, ChainBreakKind, DeedEvent, Ledger, LedgerError,
+};
+use microsociety_tree_of_life::simulation::{MicroSociety, SimCalendar};
+
+fn seeded_events() -> Vec<DeedEvent> {
+    let mut society = MicroSociety::with_seed(3, 5);
+    society
+        .set_calendar(SimCalendar::new(1_700_000_000, 60))
+        .unwrap();
+    society.simulate_cycle().unwrap();
+    society.simulate_cycle().unwrap();
+    society.ledger.all_events().to_vec()
+}
+
+#[test]
+fn test_untouched_ledger_verifies() {
+    let mut society = MicroSociety::with_seed(3, 5);
+    society.simulate_cycle().unwrap();
+    let report = society.ledger.verify();
+    assert!(report.is_valid());
+    assert_eq!(report.events_checked, 3);
+}
+
+#[test]
+fn test_verify_localizes_hash_mismatch() {
+    let mut events = seeded_events();
+    events[2].deed_type = "resource_aid_forged".to_string();
+
+    let report = verify_chain(&events);
+    let chain_break = report.first_break.unwrap();
+    assert_eq!(chain_break.index, 2);
+    assert_eq!(chain_break.kind, ChainBreakKind::HashMismatch);
+    assert_eq!(report.events_checked, 2);
+}
+
+#[test]
+fn test_verify_localizes_broken_link() {
+    let mut events = seeded_events();
+    events.remove(1);
+
+    let chain_break = verify_chain(&events).first_break.unwrap();
+    assert_eq!(chain_break.index, 1);
+    assert_eq!(chain_break.kind, ChainBreakKind::BrokenLink);
+}
+
+#[test]
+fn test_verify_rejects_truncated_prefix() {
+    let events = seeded_events();
+    let suffix = &events[2..];
+
+    let chain_break = verify_chain(suffix).first_break.unwrap();
+    assert_eq!(chain_break.index, 0);
+    assert_eq!(chain_break.kind, ChainBreakKind::BrokenLink);
+    assert_eq!(chain_break.event_id, events[2].event_id);
+
+    let mut ledger = Ledger::new();
+    assert!(matches!(
+        ledger.append(suffix[0].clone()),
+        Err(LedgerError::BrokenChain { .. })
+    ));
+
+    // A suffix does verify against the trusted prefix it continues.
+    assert!(verify_chain_from(suffix, &events[1].self_hash).is_valid());
+    let wrong_anchor = verify_chain_from(suffix, &events[0].self_hash);
+    assert_eq!(wrong_anchor.first_break.unwrap().index, 0);
+}
+
+#[test]
+fn test_verify_detects_duplicate_event_id() {
+    let mut events = seeded_events();
+    events.truncate(2);
+
+    let mut replay = events[0].clone();
+    replay.prev_hash = events[1].self_hash.clone();
+    replay.self_hash = replay.compute_hash();
+    events.push(replay);
+
+    let chain_break = verify_chain(&events).first_break.unwrap();
+    assert_eq!(chain_break.index, 2);
+    assert_eq!(chain_break.kind, ChainBreakKind::DuplicateEventId);
+    assert_eq!(chain_break.event_id, events[0].event_id);
+}
+
+#[test]
+fn test_append_rejects_bad_events_without_panicking() {
+    let events = seeded_events();
+    let mut ledger = Ledger::new();
+    ledger.append(events[0].clone()).unwrap();
+
+    let mut unlinked = events[2].clone();
+    unlinked.self_hash = unlinked.compute_hash();
+    assert!(matches!(
+        ledger.append(unlinked),
+        Err(LedgerError::BrokenChain { .. })
+    ));
+
+    let mut unhashed = events[1].clone();
+    unhashed.self_hash.clear();
+    assert!(matches!(
+        ledger.append(unhashed),
+        Err(LedgerError::EmptyHash { .. })
+    ));
+
+    let mut replay = events[0].clone();
+    replay.prev_hash = ledger.last_hash().to_string();
+    replay.self_hash = replay.compute_hash();
+    assert!(matches!(
+        ledger.append(replay),
+        Err(LedgerError::DuplicateEventId { .. })
+    ));
+
+    let mut backdated = events[1].clone();
+    backdated.timestamp = events[0].timestamp - 1;
+    backdated.self_hash = backdated.compute_hash();
+    assert!(matches!(
+        ledger.append(backdated),
+        Err(LedgerError::NonMonotonicTimestamp { .. })
+    ));
+
+    assert_eq!(ledger.all_events().len(), 1);
+    ledger.append(events[1].clone()).unwrap();
+}
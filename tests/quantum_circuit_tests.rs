@@ -0,0 +1,168 @@
+use microsociety_tree_of_life::aln_core::{CapabilityState, JurisdictionTags};
+use microsociety_tree_of_life::fate_window::FateWindowId;
+use microsociety_tree_of_life::quantum::circuit::{Circuit, Op};
+use microsociety_tree_of_life::quantum::constraints::{
+    check_plan_against_constraints, load_constraints, SynthViolation,
+};
+use microsociety_tree_of_life::quantum::qasm::{parse_qasm, QasmError, QasmErrorKind};
+use microsociety_tree_of_life::roh_model::RoHScore;
+
+const BELL_PAIRS: &str = "\
+OPENQASM 2.0;
+include \"qelib1.inc\";
+qreg a[2];
+qreg b[2];
+creg c[4];
+h a[0]; h b[0];          // layer 0
+cx a[0], a[1];           // layer 1
+cx b[0], b[1];           // layer 1
+rz(pi/4) a[1];           // layer 2
+barrier a, b;
+measure a[0] -> c[0];
+measure a[1] -> c[1];
+measure b[0] -> c[2];
+measure b[1] -> c[3];
+";
+
+#[test]
+fn test_layering_and_density_from_gate_list() {
+    let mut circuit = Circuit::new(3);
+    circuit.push(Op::gate("h", [0]));
+    circuit.push(Op::gate("cx", [0, 1]));
+    circuit.push(Op::gate("x", [2]));
+    circuit.push(Op::gate("cx", [1, 2]));
+    circuit.push(Op::Measure { qubit: 2 });
+
+    assert_eq!(
+        circuit.layers(),
+        [Some(0), Some(1), Some(0), Some(2), Some(3)]
+    );
+    let stats = circuit.stats();
+    assert_eq!(stats.depth, 4);
+    // Layers: {h, x}, {cx}, {cx}, {measure}.
+    assert!((stats.entangling_density - 0.5).abs() < 1e-6);
+    assert_eq!(stats.measurements, 1);
+
+    assert_eq!(Circuit::new(2).stats().depth, 0);
+}
+
+#[test]
+fn test_qasm_subset_reader() {
+    let circuit = parse_qasm(BELL_PAIRS).unwrap();
+    assert_eq!(circuit.num_qubits, 4);
+    assert_eq!(circuit.ops[3], Op::gate("cx", [2, 3]));
+    assert_eq!(
+        circuit.ops[5],
+        Op::Barrier {
+            qubits: vec![0, 1, 2, 3]
+        }
+    );
+
+    let stats = circuit.stats();
+    // The barrier pushes every measurement to layer 3.
+    assert_eq!(stats.depth, 4);
+    assert!((stats.entangling_density - 0.25).abs() < 1e-6);
+    assert_eq!(stats.measurements, 4);
+    assert_eq!(stats.meas_per_window(FateWindowId::new(1, 1_000), 100), 40);
+    assert_eq!(stats.meas_per_window(FateWindowId::new(1, 50), 100), 4);
+}
+
+#[test]
+fn test_qasm_errors_report_line_numbers() {
+    let error = |text: &str| parse_qasm(text).unwrap_err();
+    assert_eq!(
+        error("qreg q[2];\ncx q[0],\n   q[2];\n"),
+        QasmError {
+            line: 2,
+            kind: QasmErrorKind::IndexOutOfRange {
+                register: "q".into(),
+                index: 2,
+                size: 2
+            }
+        }
+    );
+    assert_eq!(
+        error("OPENQASM 3.0;").kind,
+        QasmErrorKind::UnsupportedVersion("3.0".into())
+    );
+    assert_eq!(
+        error("qreg q[1];\ngate g a { x a; }\n").kind,
+        QasmErrorKind::Unsupported("gate".into())
+    );
+    assert_eq!(
+        error("qreg q[2];\ncx q[1], q[1];").kind,
+        QasmErrorKind::RepeatedQubit("q[1]".into())
+    );
+    assert_eq!(
+        error("qreg q[1];\nh r[0];").kind,
+        QasmErrorKind::UnknownRegister("r".into())
+    );
+    assert_eq!(
+        error("qreg q[1];\nh q[0]\n"),
+        QasmError {
+            line: 2,
+            kind: QasmErrorKind::MissingSemicolon
+        }
+    );
+    assert_eq!(
+        error("qreg a[4294967295];\nqreg b[1];\n"),
+        QasmError {
+            line: 2,
+            kind: QasmErrorKind::TooManyQubits("b".into())
+        }
+    );
+    assert_eq!(
+        error("qreg q[1];\nrz(-(pi/4) q[0];").kind,
+        QasmErrorKind::Malformed("rz(-(pi/4) q[0]".into())
+    );
+}
+
+#[test]
+fn test_qasm_nested_parameters() {
+    let circuit =
+        parse_qasm("qreg q[2];\nrz(-(pi/4)) q[0];\nu3((pi/2), -(pi/(2*2)), 0) q[0], q[1];\n")
+            .unwrap();
+    assert_eq!(circuit.ops, [Op::gate("rz", [0]), Op::gate("u3", [0, 1])]);
+}
+
+#[test]
+fn test_layers_track_only_used_qubits() {
+    // A register this wide would need 16 GiB as a dense frontier.
+    let circuit = parse_qasm(
+        "qreg q[4294967295];\ncreg c[1];\nh q[4294967294];\ncx q[0], q[4294967294];\nmeasure q[0] -> c[0];\n",
+    )
+    .unwrap();
+    assert_eq!(circuit.num_qubits, u32::MAX);
+    assert_eq!(circuit.layers(), [Some(0), Some(1), Some(2)]);
+    assert_eq!(circuit.stats().depth, 3);
+}
+
+#[test]
+fn test_synthesized_plan_gated_end_to_end() {
+    let window = FateWindowId::new(3, 1_000);
+    let juris = JurisdictionTags::new(["EU"]);
+    let constraints = load_constraints(CapabilityState::CapLabBench, &juris, window, 0.5);
+    let stats = parse_qasm(BELL_PAIRS).unwrap().stats();
+
+    let relaxed = stats.project(window, 100, RoHScore::new(0.1), 0.2);
+    assert_eq!(
+        check_plan_against_constraints(&constraints, &relaxed),
+        Ok(())
+    );
+
+    // Shots every 5 ms put 800 measurements in the window.
+    let busy = stats.project(window, 5, RoHScore::new(0.1), 0.2);
+    assert_eq!(busy.meas_per_window, 800);
+    assert_eq!(
+        check_plan_against_constraints(&constraints, &busy),
+        Err(SynthViolation::MeasurementCadenceExceeded)
+    );
+
+    // Controlled-human work under neurorights caps entangling density at 0.2.
+    let neuro = JurisdictionTags::new(["EU", "neurorights"]);
+    let strict = load_constraints(CapabilityState::CapControlledHuman, &neuro, window, 0.5);
+    assert_eq!(
+        check_plan_against_constraints(&strict, &relaxed),
+        Err(SynthViolation::EntanglingDensityExceeded)
+    );
+}
@@ -0,0 +1,344 @@
+use microsociety_tree_of_life::aln_core::{CapabilityState, JurisdictionTags};
+use microsociety_tree_of_life::fate_window::FateWindowId;
+use microsociety_tree_of_life::quantum::constraints::{
+    check_plan_against_constraints, evaluate_plan, load_constraints, load_constraints_from,
+    load_constraints_with, ConstraintShardError, ProjectedQuantumPlanMetrics, SynthViolation,
+    SynthesisBaseline, CONSTRAINTS_SHARD,
+};
+use microsociety_tree_of_life::roh_model::{RoHScore, ROH_CEILING};
+
+fn window() -> FateWindowId {
+    FateWindowId::new(7, 1_000)
+}
+
+#[test]
+fn test_bundled_shard_formulas() {
+    let juris = JurisdictionTags::new(["EU"]);
+    let c = load_constraints(CapabilityState::CapModelOnly, &juris, window(), 0.5);
+
+    assert_eq!(c.roh_ceiling, ROH_CEILING);
+    assert_eq!(c.rod_budget, 0.5);
+    assert_eq!(c.max_depth, 128);
+    assert!((c.max_entangling_density - 0.5).abs() < 1e-6);
+    assert_eq!(c.max_meas_per_window, 50);
+    assert!((c.roh_penalty_coeff - 1.0).abs() < 1e-6);
+    assert!((c.rod_penalty_coeff - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn test_caller_supplied_baseline() {
+    let juris = JurisdictionTags::new(["neurorights"]);
+    let baseline = SynthesisBaseline {
+        max_depth: 512.0,
+        meas_interval_ms: 20.0,
+        tier_strictness: [1.0, 1.0, 1.0, 1.0],
+        neurorights_strictness: 0.5,
+        ..SynthesisBaseline::default()
+    };
+    let c = load_constraints_with(
+        &baseline,
+        CapabilityState::CapGeneralUse,
+        &juris,
+        window(),
+        0.5,
+    );
+
+    assert_eq!(c.max_depth, 128);
+    assert!((c.max_entangling_density - 0.25).abs() < 1e-6);
+    assert_eq!(c.max_meas_per_window, 25);
+    assert!((c.roh_penalty_coeff - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_rod_budget_clamped_below_one() {
+    let juris = JurisdictionTags::default();
+    let c = load_constraints(CapabilityState::CapLabBench, &juris, window(), 1.5);
+
+    assert!(c.rod_budget < 1.0);
+    assert!(c.rod_budget > 0.999);
+    assert!(c.roh_ceiling <= 0.30);
+}
+
+#[test]
+fn test_limits_tighten_with_capability_and_neurorights() {
+    let plain = JurisdictionTags::default();
+    let neuro = JurisdictionTags::new(["EU", "neurorights"]);
+    let tiers = [
+        CapabilityState::CapModelOnly,
+        CapabilityState::CapLabBench,
+        CapabilityState::CapControlledHuman,
+        CapabilityState::CapGeneralUse,
+    ];
+
+    let depths: Vec<u32> = tiers
+        .iter()
+        .map(|&cap| load_constraints(cap, &plain, window(), 0.9).max_depth)
+        .collect();
+    assert!(depths.windows(2).all(|w| w[0] > w[1]), "{depths:?}");
+
+    for cap in tiers {
+        let a = load_constraints(cap, &plain, window(), 0.9);
+        let b = load_constraints(cap, &neuro, window(), 0.9);
+        assert!(b.max_depth < a.max_depth);
+        assert!(b.max_entangling_density < a.max_entangling_density);
+        assert!(b.roh_penalty_coeff > a.roh_penalty_coeff);
+        assert_eq!(b.max_meas_per_window, a.max_meas_per_window);
+    }
+}
+
+#[test]
+fn test_shard_mismatches_are_errors() {
+    let juris = JurisdictionTags::default();
+    let load = |text: &str| {
+        let shard = aln::parse(text).unwrap();
+        load_constraints_from(
+            &shard,
+            &SynthesisBaseline::default(),
+            CapabilityState::CapModelOnly,
+            &juris,
+            window(),
+            0.5,
+        )
+    };
+
+    let swapped = CONSTRAINTS_SHARD.replace(
+        "synth_max_entangling_density(CAPABILITY.STATE, JURIS.TAGS)",
+        "synth_max_entangling_density(JURIS.TAGS, CAPABILITY.STATE)",
+    );
+    assert!(matches!(
+        load(&swapped),
+        Err(ConstraintShardError::SignatureMismatch { name, .. }) if name == "synth_max_entangling_density"
+    ));
+
+    let retyped = CONSTRAINTS_SHARD.replace("INT,  synth_max_depth", "FLOAT, synth_max_depth");
+    assert!(matches!(
+        load(&retyped),
+        Err(ConstraintShardError::ReturnTypeMismatch {
+            expected: "INT",
+            ..
+        })
+    ));
+
+    let unknown = CONSTRAINTS_SHARD.replace("roh_penalty_coeff(", "roh_penalty(");
+    assert!(matches!(
+        load(&unknown),
+        Err(ConstraintShardError::UnknownFunction { name, .. }) if name == "roh_penalty"
+    ));
+
+    let undeclared =
+        CONSTRAINTS_SHARD.replace("  JURIS.TAGS          ;", "  JURIS.BUNDLE        ;");
+    assert!(matches!(
+        load(&undeclared),
+        Err(ConstraintShardError::UndeclaredInput { name, .. }) if name == "JURIS.TAGS"
+    ));
+
+    let missing = CONSTRAINTS_SHARD.replace(
+        "ROW,FORMULA,ROD_PENALTY_COEFF,",
+        "ROW,NOTE,ROD_PENALTY_COEFF,",
+    );
+    assert!(matches!(
+        load(&missing),
+        Err(ConstraintShardError::MissingFormula("ROD_PENALTY_COEFF"))
+    ));
+
+    let tighter =
+        CONSTRAINTS_SHARD.replace("ROH.MODEL.CEILING <= 0.30", "ROH.MODEL.CEILING <= 0.25");
+    assert_eq!(load(&tighter).unwrap().roh_ceiling, 0.25);
+}
+
+#[test]
+fn test_shard_cannot_loosen_doctrine_limits() {
+    let juris = JurisdictionTags::default();
+    let load = |text: &str, rod_budget: f32| {
+        let shard = aln::parse(text).unwrap();
+        load_constraints_from(
+            &shard,
+            &SynthesisBaseline::default(),
+            CapabilityState::CapModelOnly,
+            &juris,
+            window(),
+            rod_budget,
+        )
+    };
+
+    for (from, to) in [
+        ("ROH.MODEL.CEILING <= 0.30", "ROH.MODEL.CEILING >= 0.4"),
+        ("DIAG.ROD.BUDGET < 1.0", "DIAG.ROD.BUDGET > 1.2"),
+    ] {
+        assert!(matches!(
+            load(&CONSTRAINTS_SHARD.replace(from, to), 0.5),
+            Err(ConstraintShardError::LooseningConstraint { .. })
+        ));
+    }
+
+    // Without the ceiling rows the doctrine limits still apply.
+    let bare = CONSTRAINTS_SHARD
+        .replace(
+            "ROW,CONSTRAINT,ROH_CEILING,   ROH.MODEL.CEILING <= 0.30\n",
+            "",
+        )
+        .replace("ROW,CONSTRAINT,ROD_LIMIT,     DIAG.ROD.BUDGET < 1.0\n", "");
+    assert!(!bare.contains("ROD_LIMIT"));
+    let c = load(&bare, 1.5).unwrap();
+    assert_eq!(c.roh_ceiling, ROH_CEILING);
+    assert!(c.rod_budget < 1.0);
+}
+
+#[test]
+fn test_constraint_rows_must_be_interpretable() {
+    let juris = JurisdictionTags::default();
+    let load = |text: &str| {
+        let shard = aln::parse(text).unwrap();
+        load_constraints_from(
+            &shard,
+            &SynthesisBaseline::default(),
+            CapabilityState::CapModelOnly,
+            &juris,
+            window(),
+            0.5,
+        )
+    };
+
+    for (from, to) in [
+        ("ROH.MODEL.CEILING <= 0.30", "ROH.MODEL.CEILING == 0.30"),
+        ("DIAG.ROD.BUDGET < 1.0", "DIAG.ROD.BUDGET != 1.0"),
+        (
+            "DIAG.ROD.BUDGET < 1.0",
+            "DIAG.ROD.BUDGET < ROH.MODEL.CEILING",
+        ),
+    ] {
+        assert!(
+            matches!(
+                load(&CONSTRAINTS_SHARD.replace(from, to)),
+                Err(ConstraintShardError::UnsupportedConstraint { expr, .. }) if expr == to
+            ),
+            "{to}"
+        );
+    }
+
+    // A literal on the left reads as the mirrored bound.
+    let mirrored =
+        CONSTRAINTS_SHARD.replace("ROH.MODEL.CEILING <= 0.30", "0.25 >= ROH.MODEL.CEILING");
+    assert_eq!(load(&mirrored).unwrap().roh_ceiling, 0.25);
+    let loosening = CONSTRAINTS_SHARD.replace("DIAG.ROD.BUDGET < 1.0", "1.2 < DIAG.ROD.BUDGET");
+    assert!(matches!(
+        load(&loosening),
+        Err(ConstraintShardError::LooseningConstraint { .. })
+    ));
+}
+
+#[test]
+fn test_strictness_must_be_in_unit_interval() {
+    let juris = JurisdictionTags::default();
+    let shard = aln::parse(CONSTRAINTS_SHARD).unwrap();
+    let load = |baseline: &SynthesisBaseline| {
+        load_constraints_from(
+            &shard,
+            baseline,
+            CapabilityState::CapModelOnly,
+            &juris,
+            window(),
+            0.5,
+        )
+    };
+
+    for value in [0.0, -0.5, 1.5, f32::NAN, f32::INFINITY] {
+        let mut baseline = SynthesisBaseline::default();
+        baseline.tier_strictness[2] = value;
+        assert!(
+            matches!(
+                load(&baseline),
+                Err(ConstraintShardError::InvalidStrictness { name, .. })
+                    if name == "tier_strictness[2]"
+            ),
+            "{value}"
+        );
+    }
+
+    let baseline = SynthesisBaseline {
+        neurorights_strictness: 0.0,
+        ..SynthesisBaseline::default()
+    };
+    assert!(matches!(
+        load(&baseline),
+        Err(ConstraintShardError::InvalidStrictness { name, .. }) if name == "neurorights_strictness"
+    ));
+    assert!(SynthesisBaseline::default().validate().is_ok());
+}
+
+#[test]
+fn test_plan_checked_against_loaded_constraints() {
+    let juris = JurisdictionTags::new(["neurorights"]);
+    let c = load_constraints(CapabilityState::CapControlledHuman, &juris, window(), 0.5);
+    let mut plan = ProjectedQuantumPlanMetrics {
+        projected_roh_after: RoHScore::new(0.2),
+        projected_rod: 0.3,
+        circuit_depth: c.max_depth,
+        entangling_density: c.max_entangling_density,
+        meas_per_window: c.max_meas_per_window,
+    };
+    assert_eq!(check_plan_against_constraints(&c, &plan), Ok(()));
+
+    plan.circuit_depth += 1;
+    assert_eq!(
+        check_plan_against_constraints(&c, &plan),
+        Err(SynthViolation::DepthLimitExceeded)
+    );
+
+    plan.projected_roh_after = RoHScore::new(0.31);
+    assert_eq!(
+        check_plan_against_constraints(&c, &plan),
+        Err(SynthViolation::RoHCeilingWouldBeExceeded)
+    );
+}
+
+#[test]
+fn test_report_lists_every_limit_with_slack() {
+    let juris = JurisdictionTags::default();
+    let c = load_constraints(CapabilityState::CapModelOnly, &juris, window(), 0.5);
+    let plan = ProjectedQuantumPlanMetrics {
+        projected_roh_after: RoHScore::new(0.1),
+        projected_rod: 0.5,
+        circuit_depth: 130,
+        entangling_density: 0.6,
+        meas_per_window: 50,
+    };
+
+    let report = evaluate_plan(&c, &plan);
+    assert!(!report.passed());
+    assert_eq!(report.checks.len(), 5);
+    let failed: Vec<_> = report.violations().map(|v| v.violation).collect();
+    assert_eq!(
+        failed,
+        [
+            SynthViolation::RODBudgetWouldBeExceeded,
+            SynthViolation::DepthLimitExceeded,
+            SynthViolation::EntanglingDensityExceeded,
+        ]
+    );
+
+    let depth = &report.checks[2];
+    assert_eq!(
+        (depth.limit, depth.observed, depth.slack),
+        (128.0, 130.0, -2.0)
+    );
+    assert_eq!(
+        depth.to_string(),
+        "circuit depth 130 > limit 128 (slack -2)"
+    );
+    // The ROD budget is strict: zero slack is already a violation.
+    assert_eq!(report.checks[1].slack, 0.0);
+    let meas = &report.checks[4];
+    assert!(meas.satisfied);
+    assert_eq!(meas.slack, 0.0);
+    assert!((report.checks[0].slack - 0.2).abs() < 1e-6);
+
+    assert_eq!(
+        check_plan_against_constraints(&c, &plan),
+        Err(report.first_violation().unwrap())
+    );
+    assert_eq!(
+        report.first_violation(),
+        Some(SynthViolation::RODBudgetWouldBeExceeded)
+    );
+}
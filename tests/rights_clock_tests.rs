@@ -0,0 +1,161 @@
+use std::process::Command;
+use std::sync::Arc;
+
+use approx::assert_relative_eq;
+
+use microsociety_tree_of_life::ledger::{ChurchAccountState, InMemoryKeystore, Ledger};
+use microsociety_tree_of_life::simulation::MicroSociety;
+use microsociety_tree_of_life::utils::time::{
+    time_discount_factor_with_tau, FixedClock, SimulatedClock,
+};
+
+const START: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+fn society_on_simulated_clock() -> MicroSociety {
+    let clock = Arc::new(SimulatedClock::starting_at(START));
+    let mut society = MicroSociety::with_seed(4, 3);
+    society.set_clock(Box::new(clock.clone()));
+    for _ in 0..5 {
+        society.simulate_cycle().unwrap();
+        clock.advance(3_600);
+    }
+    society
+}
+
+#[test]
+fn test_events_are_stamped_by_injected_clock() {
+    let society = society_on_simulated_clock();
+    let events = society.ledger.all_events();
+    assert_eq!(events[0].timestamp, START);
+    assert_eq!(events[events.len() - 1].timestamp, START + 4 * 3_600);
+}
+
+#[test]
+fn test_rights_score_is_reproducible_as_of_fixed_instant() {
+    let society = society_on_simulated_clock();
+    let as_of = FixedClock(START + 2 * DAY);
+
+    let a = ChurchAccountState::compute_from_ledger_with_clock(
+        &society.ledger,
+        "agent_1",
+        &as_of,
+        DAY as f64,
+    )
+    .unwrap()
+    .unwrap();
+    let b = ChurchAccountState::compute_from_ledger_with_clock(
+        &society.ledger,
+        "agent_1",
+        &as_of,
+        DAY as f64,
+    )
+    .unwrap()
+    .unwrap();
+    assert_relative_eq!(a.existence_rights_score, b.existence_rights_score);
+    assert_relative_eq!(a.cumulative_good_deeds, b.cumulative_good_deeds);
+}
+
+#[test]
+fn test_longer_tau_discounts_less() {
+    let society = society_on_simulated_clock();
+    let as_of = FixedClock(START + 3 * DAY);
+
+    let short = ChurchAccountState::compute_from_ledger_with_clock(
+        &society.ledger,
+        "agent_0",
+        &as_of,
+        DAY as f64,
+    )
+    .unwrap()
+    .unwrap();
+    let long = ChurchAccountState::compute_from_ledger_with_clock(
+        &society.ledger,
+        "agent_0",
+        &as_of,
+        30.0 * DAY as f64,
+    )
+    .unwrap()
+    .unwrap();
+    assert!(long.cumulative_good_deeds >= short.cumulative_good_deeds);
+}
+
+#[test]
+fn test_invalid_tau_is_rejected() {
+    let society = society_on_simulated_clock();
+    for tau in [0.0, -(DAY as f64), f64::NAN, f64::INFINITY] {
+        assert!(time_discount_factor_with_tau(60, tau).is_err(), "{tau}");
+        assert!(ChurchAccountState::compute_from_ledger_with_clock(
+            &society.ledger,
+            "agent_0",
+            &FixedClock(START),
+            tau,
+        )
+        .is_err());
+    }
+    assert_relative_eq!(
+        time_discount_factor_with_tau(DAY, DAY as f64).unwrap(),
+        (-1.0f64).exp()
+    );
+}
+
+#[test]
+fn test_signed_rights_score_as_of_fixed_instant() {
+    let society = society_on_simulated_clock();
+    let mut keys = InMemoryKeystore::new();
+    keys.generate("agent_0");
+    let mut ledger = Ledger::new();
+    for event in society.ledger.all_events() {
+        if event.actor_id == "agent_0" {
+            ledger.append_signed(event.clone(), "agent_0", &keys).unwrap();
+        } else {
+            ledger.append(event.clone()).unwrap();
+        }
+    }
+
+    let as_of = FixedClock(START + 2 * DAY);
+    let signed = ChurchAccountState::compute_from_ledger_signed_with_clock(
+        &ledger,
+        "agent_0",
+        &keys,
+        &as_of,
+        DAY as f64,
+    )
+    .unwrap()
+    .unwrap();
+    let unsigned =
+        ChurchAccountState::compute_from_ledger_with_clock(&ledger, "agent_0", &as_of, DAY as f64)
+            .unwrap()
+            .unwrap();
+    // Every agent_0 event is signed, so both views agree exactly.
+    assert_eq!(signed.cumulative_good_deeds, unsigned.cumulative_good_deeds);
+    assert_eq!(signed.existence_rights_score, unsigned.existence_rights_score);
+}
+
+fn run_cli(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_microsociety_tree_of_life"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_saved_ledger_scores_like_the_warmup_that_wrote_it() {
+    let path = std::env::temp_dir().join(format!("rights-{}.jsonl", uuid::Uuid::new_v4()));
+    let path = path.to_str().unwrap();
+
+    run_cli(&[
+        "simulate-cycles", "--cycles", "4", "--agent-count", "3", "--seed", "1", "--ledger", path,
+    ]);
+    let saved = run_cli(&["compute-rights", "--agent-id", "agent_1", "--ledger", path]);
+    let warmup = run_cli(&[
+        "compute-rights", "--agent-id", "agent_1", "--agent-count", "3", "--seed", "1",
+        "--warmup-cycles", "4",
+    ]);
+    assert!(saved.starts_with("Advisory Rights-to-Exist score for agent_1"));
+    assert_eq!(saved, warmup);
+
+    std::fs::remove_file(path).unwrap();
+}
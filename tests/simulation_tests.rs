@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use approx::assert_relative_eq;
 
 use microsociety_tree_of_life::ledger::ChurchAccountState;
 use microsociety_tree_of_life::simulation::{MicroAgent, MicroSociety};
+use microsociety_tree_of_life::utils::time::SimulatedClock;
 
 #[test]
 fn test_agent_update_predicates() {
@@ -24,11 +27,12 @@ fn test_agent_update_predicates() {
 #[test]
 fn test_society_simulation_creates_events() {
     let mut society = MicroSociety::new(5);
-    society.simulate_cycle();
+    society.simulate_cycle().unwrap();
     assert_eq!(society.ledger.all_events().len(), 5);
 }
 
 #[test]
+#[allow(clippy::field_reassign_with_default)]
 fn test_rights_score_bounded_and_computed() {
     let mut state = ChurchAccountState::default();
     state.eco_score = 0.8;
@@ -39,3 +43,46 @@ fn test_rights_score_bounded_and_computed() {
     let clamped = existence.clamp(0.0, 1.0);
     assert_relative_eq!(clamped, 0.8 * 0.8 * 0.9, epsilon = 1e-6);
 }
+
+#[test]
+fn test_seeded_societies_are_reproducible() {
+    let run = |seed: u64| {
+        let mut society = MicroSociety::with_seed(6, seed);
+        for _ in 0..4 {
+            society.simulate_cycle().unwrap();
+        }
+        society
+            .ledger
+            .all_events()
+            .iter()
+            .map(|e| {
+                (
+                    e.event_id.clone(),
+                    e.deed_type.clone(),
+                    e.context_json.to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(run(42), run(42));
+    assert_ne!(run(42), run(43));
+}
+
+#[test]
+fn test_seeded_ledgers_are_byte_identical() {
+    let run = |seed: u64| {
+        let clock = Arc::new(SimulatedClock::starting_at(1_700_000_000));
+        let mut society = MicroSociety::with_seed(6, seed);
+        society.set_clock(Box::new(clock.clone()));
+        for _ in 0..4 {
+            society.simulate_cycle().unwrap();
+            clock.advance(3_600);
+        }
+        serde_json::to_vec(society.ledger.all_events()).unwrap()
+    };
+
+    let first = run(42);
+    assert_eq!(first, run(42));
+    assert_ne!(first, run(43));
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::site_society;
+
+#[test]
+fn test_agents_occupy_sites() {
+    let society = site_society(4, 1);
+    assert_eq!(society.agents.len(), 4);
+    for (i, agent) in society.agents.iter().enumerate() {
+        assert_eq!(agent.site, Some(i as u64));
+    }
+}
+
+#[test]
+fn test_every_validated_deed_is_mirrored_into_ledger() {
+    let mut society = site_society(4, 2);
+    for _ in 0..5 {
+        society.simulate_cycle().unwrap();
+    }
+
+    let events = society.ledger.all_events();
+    assert_eq!(events.len(), 4 * 5);
+    assert!(society.ledger.verify().is_valid());
+
+    for event in events {
+        assert!(event.tags.contains(&"jetson_line".to_string()));
+        let validated = &event.context_json["validated_deed"];
+        assert_eq!(validated["tick"], event.tick.unwrap());
+        assert_eq!(
+            validated["deed_type"].as_str().unwrap(),
+            event.deed_type.as_str()
+        );
+        let blocked = validated["status"] == "Blocked";
+        assert_eq!(blocked, event.ethics_flags.contains(&"blocked".to_string()));
+    }
+}
+
+#[test]
+fn test_ledger_post_state_matches_world() {
+    let mut society = site_society(3, 3);
+    society.simulate_cycle().unwrap();
+
+    let world = society.world().unwrap();
+    let last = society.ledger.all_events().last().unwrap();
+    let validated = &last.context_json["validated_deed"];
+    let source = validated["source_site"].as_u64().unwrap() as usize;
+    let post_church = validated["post_source"]["church"].as_f64().unwrap();
+    assert_eq!(post_church, world.sites[source].church);
+}
@@ -0,0 +1,157 @@
+mod common;
+
+use common::{invariants, world};
+use microsociety_tree_of_life::deeds::{
+    DeedRequest, DeedStatus, DeedType, InvariantOutcome, ValidationKernel,
+};
+use microsociety_tree_of_life::simulation::MicroSociety;
+use microsociety_tree_of_life::topology::Topology;
+
+fn request(deed_type: DeedType, source: u64, target: Option<u64>) -> DeedRequest {
+    DeedRequest {
+        tick: 0,
+        deed_id: 0,
+        proposer: source,
+        deed_type,
+        source_site: source,
+        target_site: target,
+        intensity: 0.5,
+    }
+}
+
+#[test]
+fn test_neighbors_and_distances() {
+    let ids = |t: Topology, site, n| -> Vec<u64> {
+        t.neighbors(site, n).into_iter().map(|(id, _)| id).collect()
+    };
+
+    assert_eq!(ids(Topology::line(), 0, 5), vec![1]);
+    assert_eq!(ids(Topology::ring(), 0, 5), vec![1, 4]);
+    assert_eq!(ids(Topology::ring().with_radius(2), 0, 5), vec![1, 2, 3, 4]);
+    assert_eq!(Topology::ring().distance(0, 4, 5), Some(1));
+    assert_eq!(Topology::line().distance(0, 4, 5), Some(4));
+    assert_eq!(Topology::line().distance(0, 5, 5), None);
+
+    // 3x3 grid: the centre has four von Neumann neighbors.
+    assert_eq!(ids(Topology::grid(3), 4, 9), vec![1, 3, 5, 7]);
+    assert_eq!(Topology::grid(3).distance(0, 8, 9), Some(4));
+}
+
+#[test]
+fn test_neighbors_match_distance_scan() {
+    let topologies = [
+        Topology::line(),
+        Topology::line().with_radius(3),
+        Topology::ring(),
+        Topology::ring().with_radius(2),
+        Topology::ring().with_radius(9),
+        Topology::grid(3),
+        Topology::grid(4).with_radius(2),
+        Topology::grid(1).with_radius(2),
+    ];
+    for topology in topologies {
+        for n in 0..12usize {
+            for site in 0..n as u64 {
+                let scanned: Vec<(u64, u64)> = (0..n as u64)
+                    .filter(|&other| topology.in_range(site, other, n))
+                    .map(|other| (other, topology.distance(site, other, n).unwrap()))
+                    .collect();
+                assert_eq!(
+                    topology.neighbors(site, n),
+                    scanned,
+                    "{topology:?} n={n} site={site}"
+                );
+            }
+        }
+    }
+
+    // Enumerating offsets keeps huge worlds cheap.
+    let huge = 1usize << 40;
+    assert_eq!(
+        Topology::line().neighbors(1 << 20, huge),
+        vec![((1 << 20) - 1, 1), ((1 << 20) + 1, 1)]
+    );
+    assert_eq!(Topology::grid(1 << 20).neighbors(0, huge).len(), 2);
+}
+
+#[test]
+fn test_out_of_range_targets_are_blocked() {
+    let kernel = ValidationKernel::with_topology(invariants(), Topology::line());
+    let mut w = world(4);
+
+    for deed_type in [DeedType::Help, DeedType::Conflict, DeedType::Colonize] {
+        let far = kernel
+            .process_deed(&mut w, request(deed_type, 0, Some(3)))
+            .unwrap();
+        assert_eq!(far.status, DeedStatus::Blocked);
+        assert!(far.reason.contains("out of range"));
+    }
+
+    let near = kernel
+        .process_deed(&mut w, request(DeedType::Help, 0, Some(1)))
+        .unwrap();
+    assert_ne!(near.status, DeedStatus::Blocked);
+}
+
+#[test]
+fn test_pollution_spills_over_topology_neighbors() {
+    let kernel = ValidationKernel::with_topology(invariants(), Topology::line().with_radius(2));
+    let mut w = world(5);
+
+    let deed = kernel
+        .process_deed(&mut w, request(DeedType::EmitPollution, 2, Some(3)))
+        .unwrap();
+    assert_eq!(deed.status, DeedStatus::Success);
+
+    let spilled: Vec<u64> = deed.spillover.iter().map(|s| s.site).collect();
+    assert_eq!(spilled, vec![0, 1, 4]);
+    assert!(w.sites[1].pollution > w.sites[0].pollution);
+    assert!(w.sites[0].pollution > 0.0);
+    assert_eq!(w.sites[3].pollution, w.sites[1].pollution);
+}
+
+#[test]
+fn test_spillover_respects_neighbor_ceilings() {
+    let kernel = ValidationKernel::with_topology(invariants(), Topology::line());
+
+    // Site 1 has room for 0.001 BIOLOAD: the full deed would spill 0.0025.
+    let mut w = world(4);
+    w.sites[1].bioload = 0.999;
+    let deed = kernel
+        .process_deed(&mut w, request(DeedType::EmitPollution, 2, Some(3)))
+        .unwrap();
+    assert_eq!(deed.status, DeedStatus::Transformed);
+    assert_eq!(deed.deed_type, DeedType::EmitPollution);
+    assert!((deed.intensity - 0.2).abs() < 1e-9);
+    assert!(matches!(
+        deed.outcomes[0],
+        InvariantOutcome::SpilloverBioloadCeiling { site: 1, .. }
+    ));
+    assert!(w.sites[1].bioload <= 1.0);
+
+    // A neighbor already over the ceiling cannot take any more.
+    let mut w = world(4);
+    w.sites[1].bioload = 1.2;
+    let deed = kernel
+        .process_deed(&mut w, request(DeedType::EmitPollution, 2, Some(3)))
+        .unwrap();
+    assert_eq!(deed.status, DeedStatus::Blocked);
+    assert!(deed.reason.contains("spillover BIOLOAD"));
+    assert_eq!(w.sites[1].bioload, 1.2);
+    assert_eq!(w.sites[2].pollution, 0.0);
+}
+
+#[test]
+fn test_society_follows_topology() {
+    let mut society = MicroSociety::with_seed(4, 9);
+    society.set_topology(Topology::line());
+    society.simulate_cycle().unwrap();
+
+    let targets: Vec<_> = society
+        .ledger
+        .all_events()
+        .iter()
+        .map(|e| e.target_ids[0].clone())
+        .collect();
+    assert_eq!(targets, vec!["agent_1", "agent_2", "agent_3", "agent_2"]);
+}
@@ -0,0 +1,85 @@
+use approx::assert_relative_eq;
+
+mod common;
+
+use common::site_society;
+use microsociety_tree_of_life::deeds::WorldLine;
+use microsociety_tree_of_life::tokens::ExtendedTokenState;
+use microsociety_tree_of_life::topology::Topology;
+use microsociety_tree_of_life::transport::{transport_step, TransportParams};
+
+fn params(rate: f64) -> TransportParams {
+    TransportParams {
+        pollution_diffusion: rate,
+        fear_diffusion: rate,
+        exposure_local: 1.0,
+        exposure_neighbor: 0.5,
+    }
+}
+
+fn world_with_hotspot(n: usize, hot: usize) -> WorldLine {
+    let mut sites = vec![ExtendedTokenState::zero(); n];
+    sites[hot].pollution = 1.0;
+    sites[hot].fear = 0.6;
+    WorldLine { sites }
+}
+
+fn total(world: &WorldLine, f: impl Fn(&ExtendedTokenState) -> f64) -> f64 {
+    world.sites.iter().map(f).sum()
+}
+
+#[test]
+fn test_diffusion_conserves_pollution_and_fear() {
+    for topology in [
+        Topology::line(),
+        Topology::ring().with_radius(2),
+        Topology::grid(3),
+    ] {
+        let mut world = world_with_hotspot(9, 0);
+        for _ in 0..20 {
+            transport_step(&mut world, &topology, &params(1.0));
+            assert_relative_eq!(total(&world, |s| s.pollution), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(total(&world, |s| s.fear), 0.6, epsilon = 1e-12);
+            assert!(world
+                .sites
+                .iter()
+                .all(|s| s.pollution >= 0.0 && s.fear >= 0.0 && s.fear <= 0.6));
+        }
+    }
+}
+
+#[test]
+fn test_exposure_comes_from_local_and_neighboring_pollution() {
+    let mut world = world_with_hotspot(4, 1);
+    let no_diffusion = TransportParams {
+        pollution_diffusion: 0.0,
+        fear_diffusion: 0.0,
+        ..params(0.0)
+    };
+    transport_step(&mut world, &Topology::line(), &no_diffusion);
+
+    assert_relative_eq!(world.sites[1].exposure, 1.0);
+    // Site 0's only neighbor is the hotspot; site 2 averages it with site 3.
+    assert_relative_eq!(world.sites[0].exposure, 0.5);
+    assert_relative_eq!(world.sites[2].exposure, 0.25);
+    assert_relative_eq!(world.sites[3].exposure, 0.0);
+
+    // FEAR dynamics now see a non-zero EXPOSURE term.
+    let mut site = world.sites[2];
+    site.update_fear(0.0, 0.1, 0.0);
+    assert!(site.fear > 0.0);
+}
+
+#[test]
+fn test_site_society_runs_transport_each_cycle() {
+    let mut society = site_society(5, 4);
+    society.set_transport(params(0.5));
+    for _ in 0..4 {
+        society.simulate_cycle().unwrap();
+    }
+
+    let world = society.world().unwrap();
+    for (agent, tokens) in society.agents.iter().zip(&world.sites) {
+        assert_relative_eq!(agent.tree_snapshot.pain, tokens.exposure.min(1.0));
+    }
+}
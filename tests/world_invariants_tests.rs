@@ -0,0 +1,132 @@
+mod common;
+
+use common::{invariants, world};
+use microsociety_tree_of_life::deeds::{
+    DeedRequest, DeedStatus, DeedType, ValidationKernel, WorldLine,
+};
+use microsociety_tree_of_life::jetson_line::JetsonLineSim;
+use microsociety_tree_of_life::spectral::SpectralParams;
+use microsociety_tree_of_life::world_invariants::{
+    GlobalInvariant, GlobalInvariantParams, WorldTotals,
+};
+
+fn global() -> GlobalInvariantParams {
+    GlobalInvariantParams {
+        power_church_ratio: 1.0,
+        bioload_max_total: 10.0,
+        fear_max_mean: 0.5,
+    }
+}
+
+/// Every site sits exactly on the local cap POWER = CHURCH.
+fn world_at_power_budget(n: usize) -> WorldLine {
+    let mut world = world(n);
+    for site in &mut world.sites {
+        site.church = 0.5;
+    }
+    world
+}
+
+fn request(deed_type: DeedType, target: Option<u64>, intensity: f64) -> DeedRequest {
+    DeedRequest {
+        tick: 0,
+        deed_id: 0,
+        proposer: 0,
+        deed_type,
+        source_site: 0,
+        target_site: target,
+        intensity,
+    }
+}
+
+#[test]
+fn test_check_reports_each_global_violation() {
+    let mut world = world_at_power_budget(4);
+    world.sites[0].power = 1.0;
+    world.sites[1].bioload = 11.0;
+    world.sites[2].fear = 4.0;
+
+    let violations = global().check(&WorldTotals::of(&world));
+    let kinds: Vec<_> = violations.iter().map(|v| v.invariant).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            GlobalInvariant::PowerBudget,
+            GlobalInvariant::TotalBioload,
+            GlobalInvariant::AggregateFear
+        ]
+    );
+    assert_eq!(violations[0].limit, 2.0);
+    assert_eq!(violations[0].observed, 2.5);
+}
+
+#[test]
+fn test_deed_breaking_power_budget_is_blocked_and_rolled_back() {
+    let mut kernel = ValidationKernel::new(invariants());
+    kernel.global = Some(global());
+    let mut world = world_at_power_budget(3);
+
+    // Conflict burns CHURCH on both sides while POWER stays, so the line as a
+    // whole goes over budget even though each site is re-capped locally only
+    // at the source.
+    let deed = kernel
+        .process_deed(&mut world, request(DeedType::Conflict, Some(1), 1.0))
+        .unwrap();
+    assert_eq!(deed.status, DeedStatus::Blocked);
+    assert!(deed.reason.contains("global POWER budget"));
+    assert_eq!(world.sites[1].church, 0.5);
+    assert_eq!(world.sites[0].church, 0.5);
+
+    // Without global checks the same deed goes through.
+    let local_only = ValidationKernel::new(invariants());
+    let deed = local_only
+        .process_deed(&mut world, request(DeedType::Conflict, Some(1), 1.0))
+        .unwrap();
+    assert_ne!(deed.status, DeedStatus::Blocked);
+    assert!(!global().check(&WorldTotals::of(&world)).is_empty());
+}
+
+#[test]
+fn test_repair_allowed_in_already_violating_world() {
+    let mut kernel = ValidationKernel::new(invariants());
+    kernel.global = Some(global());
+    let mut world = world_at_power_budget(2);
+    world.sites[1].fear = 2.0;
+
+    let deed = kernel
+        .process_deed(&mut world, request(DeedType::Repair, None, 0.5))
+        .unwrap();
+    assert_ne!(deed.status, DeedStatus::Blocked);
+}
+
+#[test]
+fn test_tick_report_lists_remaining_global_violations() {
+    let mut world = world_at_power_budget(2);
+    world.sites[1].bioload = 20.0;
+    let mut sim = JetsonLineSim::new(
+        world,
+        invariants(),
+        SpectralParams {
+            death_high: 1.0,
+            life_low: 1e-9,
+            decay_high: 0.8,
+            lifeforce_low: 0.1,
+            justice_low: 0.1,
+            habit_high: 5.0,
+            fear_low_band: 0.0,
+            fear_high_band: 0.6,
+            fear_window_length: 3,
+            twistof_fate_window: 10,
+            twistof_fate_severity_threshold: 0.5,
+            bioload_max_site: 1.0,
+        },
+    );
+    sim.set_global_invariants(global());
+
+    let report = sim.step();
+    assert_eq!(report.global_violations.len(), 1);
+    assert_eq!(
+        report.global_violations[0].invariant,
+        GlobalInvariant::TotalBioload
+    );
+}